@@ -176,6 +176,85 @@ pub fn apply_internal_enum_variant_tag(
     }
 }
 
+/// Create a schema for the tag of a `#[serde(other)]` enum variant, which matches any string other
+/// than the tags of the enum's other variants.
+pub fn other_enum_variant_tag_schema(other_tags: &[&str]) -> Schema {
+    if other_tags.is_empty() {
+        return json_schema!({ "type": "string" });
+    }
+
+    json_schema!({
+        "type": "string",
+        "not": {
+            "enum": other_tags
+        }
+    })
+}
+
+/// Move the schema for a variant of an internally tagged enum into the generator's definitions (so
+/// that it can be referenced from a `discriminator`'s `mapping`), returning a `$ref` schema.
+pub fn new_discriminator_variant_ref(
+    generator: &mut SchemaGenerator,
+    name: &str,
+    schema: Schema,
+) -> Schema {
+    if generator.settings().inline_subschemas {
+        return schema;
+    }
+
+    generator.insert_named_definition(name, schema)
+}
+
+/// Add an OpenAPI `discriminator` object to the schema for an internally tagged enum, mapping the
+/// tag of each variant referenced in the enum's `oneOf` to its definition.
+pub fn insert_discriminator(generator: &SchemaGenerator, schema: &mut Schema, tag_name: &str) {
+    let prefix = format!("#{}/", generator.definitions_path_stripped());
+    let mut mapping = Map::new();
+
+    for subschema in schema
+        .get("oneOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let Some(reference) = subschema.get("$ref").and_then(Value::as_str) else {
+            continue;
+        };
+        let tag = reference
+            .strip_prefix(&prefix)
+            .and_then(|name| generator.definitions().get(name))
+            .and_then(|definition| variant_tag(definition, tag_name));
+        if let Some(tag) = tag {
+            mapping.insert(tag.to_owned(), reference.into());
+        }
+    }
+
+    let mut discriminator = Map::new();
+    discriminator.insert("propertyName".to_owned(), tag_name.into());
+    if !mapping.is_empty() {
+        discriminator.insert("mapping".to_owned(), mapping.into());
+    }
+
+    schema.insert("discriminator".to_owned(), discriminator.into());
+}
+
+// Finds the tag value of a variant definition. The definition may already have been transformed,
+// e.g. replacing `const` with a single-valued `enum`, or moving `properties` into an `allOf`.
+fn variant_tag<'a>(definition: &'a Value, tag_name: &str) -> Option<&'a str> {
+    if let Some(tag_schema) = definition.get("properties").and_then(|p| p.get(tag_name)) {
+        return match tag_schema.get("enum").and_then(Value::as_array) {
+            Some(values) if values.len() == 1 => values[0].as_str(),
+            _ => tag_schema.get("const")?.as_str(),
+        };
+    }
+
+    definition
+        .get("allOf")?
+        .as_array()?
+        .iter()
+        .find_map(|subschema| variant_tag(subschema, tag_name))
+}
+
 pub fn insert_object_property(
     schema: &mut Schema,
     key: &str,
@@ -202,6 +281,71 @@ pub fn insert_object_property(
     }
 }
 
+/// Like `insert_object_property`, but also inserts the property under each of the given `aliases`
+/// (which should be empty for the serialize contract). When the property is required, exactly one
+/// of the property name or its aliases must be present.
+pub fn insert_object_property_with_aliases(
+    schema: &mut Schema,
+    key: &str,
+    aliases: &[&str],
+    is_optional: bool,
+    sub_schema: Schema,
+) {
+    if aliases.is_empty() {
+        return insert_object_property(schema, key, is_optional, sub_schema);
+    }
+
+    for alias in aliases {
+        insert_object_property(schema, alias, true, sub_schema.clone());
+    }
+    insert_object_property(schema, key, true, sub_schema);
+
+    if !is_optional {
+        let names: Vec<&str> = core::iter::once(key)
+            .chain(aliases.iter().copied())
+            .collect();
+        insert_one_of_required(schema, &names);
+    }
+}
+
+pub fn insert_dependent_required(schema: &mut Schema, key: &str, required: &[&str]) {
+    if let Some(dependent_required) = schema
+        .ensure_object()
+        .entry("dependentRequired")
+        .or_insert(Value::Object(Map::new()))
+        .as_object_mut()
+    {
+        if let Some(existing) = dependent_required
+            .entry(key)
+            .or_insert(Value::Array(Vec::new()))
+            .as_array_mut()
+        {
+            for r in required {
+                if !existing.iter().any(|e| e == r) {
+                    existing.push((*r).into());
+                }
+            }
+        }
+    }
+}
+
+pub fn insert_one_of_required(schema: &mut Schema, names: &[&str]) {
+    let alternatives: Vec<Value> = names.iter().map(|k| json!({ "required": [k] })).collect();
+
+    let obj = schema.ensure_object();
+    if obj.contains_key("oneOf") {
+        if let Some(all_of) = obj
+            .entry("allOf")
+            .or_insert(Value::Array(Vec::new()))
+            .as_array_mut()
+        {
+            all_of.push(json!({ "oneOf": alternatives }));
+        }
+    } else {
+        obj.insert("oneOf".to_owned(), alternatives.into());
+    }
+}
+
 pub fn insert_metadata_property(schema: &mut Schema, key: &str, value: impl Into<Value>) {
     schema.ensure_object().insert(key.to_owned(), value.into());
 }
@@ -228,11 +372,102 @@ pub fn insert_validation_property(
     }
 }
 
+pub fn insert_content_schema<T: ?Sized + JsonSchema>(
+    generator: &mut SchemaGenerator,
+    schema: &mut Schema,
+) {
+    if schema.has_type("string") {
+        let content_schema = generator.subschema_for::<T>();
+        schema.insert("contentSchema".to_owned(), content_schema.into());
+    }
+}
+
 pub fn must_contain(schema: &mut Schema, substring: &str) {
     let escaped = regex_syntax::escape(substring);
+    if schema.has_type("array") {
+        if let Some(contains) = contains_schema(schema, &escaped) {
+            schema.insert("contains".to_owned(), contains);
+        }
+    }
     insert_validation_property(schema, "string", "pattern", escaped);
 }
 
+// Arrays only get a `contains` schema if their items are strings, because a `pattern` is ignored
+// for any other type of value. The `contains` schema is based on the `items` schema, so that it can
+// only match items that are valid elements of the array.
+fn contains_schema(schema: &Schema, pattern: &str) -> Option<Value> {
+    let items = schema
+        .get("items")
+        .and_then(|i| <&Schema>::try_from(i).ok())
+        .filter(|i| i.has_type("string"))?;
+
+    let mut contains = match items.as_object() {
+        Some(obj) if !obj.contains_key("$ref") => obj.clone(),
+        Some(obj) => json!({ "allOf": [obj] }).as_object().cloned()?,
+        None => Map::new(),
+    };
+    contains.insert("pattern".to_owned(), pattern.into());
+    Some(contains.into())
+}
+
+// `minContains` and `maxContains` have no effect without `contains`, so are only set alongside it.
+pub fn insert_contains_count(schema: &mut Schema, key: &str, value: impl Into<Value>) {
+    if schema.get("contains").is_some() {
+        schema.insert(key.to_owned(), value.into());
+    }
+}
+
+pub fn must_not_contain(schema: &mut Schema, substring: &str) {
+    if !schema.has_type("string") {
+        return;
+    }
+
+    let not_schema = json!({ "pattern": regex_syntax::escape(substring) });
+    let obj = schema.ensure_object();
+    if obj.contains_key("not") {
+        if let Some(all_of) = obj
+            .entry("allOf")
+            .or_insert(Value::Array(Vec::new()))
+            .as_array_mut()
+        {
+            all_of.push(json!({ "not": not_schema }));
+        }
+    } else {
+        obj.insert("not".to_owned(), not_schema);
+    }
+}
+
+pub fn must_start_with(schema: &mut Schema, prefix: &str) {
+    let escaped = regex_syntax::escape(prefix);
+    insert_pattern(schema, format!("^{escaped}"));
+}
+
+pub fn must_end_with(schema: &mut Schema, suffix: &str) {
+    let escaped = regex_syntax::escape(suffix);
+    insert_pattern(schema, format!("{escaped}$"));
+}
+
+// Like `insert_validation_property(schema, "string", "pattern", pattern)`, but if the schema
+// already has a `pattern`, then the new pattern is added in an `allOf` so both are applied.
+pub fn insert_pattern(schema: &mut Schema, pattern: impl Into<Value>) {
+    if !schema.has_type("string") {
+        return;
+    }
+
+    let obj = schema.ensure_object();
+    if obj.contains_key("pattern") {
+        if let Some(all_of) = obj
+            .entry("allOf")
+            .or_insert(Value::Array(Vec::new()))
+            .as_array_mut()
+        {
+            all_of.push(json!({ "pattern": pattern.into() }));
+        }
+    } else {
+        obj.insert("pattern".to_owned(), pattern.into());
+    }
+}
+
 pub fn apply_inner_validation(schema: &mut Schema, f: fn(&mut Schema) -> ()) {
     if let Some(inner_schema) = schema
         .as_object_mut()
@@ -243,6 +478,60 @@ pub fn apply_inner_validation(schema: &mut Schema, f: fn(&mut Schema) -> ()) {
     }
 }
 
+pub fn apply_map_key_validation(schema: &mut Schema, f: fn(&mut Schema) -> ()) {
+    if !schema.has_type("object") {
+        return;
+    }
+
+    // Map keys are always strings in JSON, so if the key schema isn't already constrained, start
+    // from a plain string schema.
+    let plain_key_schema = json!({ "type": "string" });
+    let Some(key_value) = schema
+        .as_object_mut()
+        .map(|o| o.entry("propertyNames").or_insert(plain_key_schema.clone()))
+    else {
+        return;
+    };
+
+    if let Ok(key_schema) = <&mut Schema>::try_from(&mut *key_value) {
+        // Validation properties are only added to schemas with a string `type`, so a key schema
+        // without one (e.g. a `$ref` to an enum's schema) is wrapped in an `allOf`.
+        if key_schema.has_type("string") {
+            f(key_schema);
+        } else {
+            let inner = core::mem::take(key_schema);
+            let mut wrapped = json_schema!({
+                "type": "string",
+                "allOf": [inner],
+            });
+            f(&mut wrapped);
+            if wrapped.as_object().is_some_and(|o| o.len() > 2) {
+                *key_schema = wrapped;
+            } else if let Some(Value::Array(mut all_of)) = wrapped.remove("allOf") {
+                *key_value = all_of.pop().unwrap_or_default();
+            }
+        }
+    }
+
+    if *key_value == plain_key_schema {
+        schema.remove("propertyNames");
+    }
+}
+
+pub fn apply_map_value_validation(schema: &mut Schema, f: fn(&mut Schema) -> ()) {
+    if !schema.has_type("object") {
+        return;
+    }
+
+    if let Some(value_schema) = schema
+        .as_object_mut()
+        .and_then(|o| o.get_mut("additionalProperties"))
+        .and_then(|v| v.try_into().ok())
+    {
+        f(value_schema);
+    }
+}
+
 pub fn flatten(schema: &mut Schema, other: Schema) {
     fn flatten_property(obj1: &mut Map<String, Value>, key: String, value2: Value) {
         match obj1.entry(key) {
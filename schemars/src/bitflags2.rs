@@ -0,0 +1,70 @@
+/*!
+Marker type for [`bitflags`](https://docs.rs/bitflags/2) types.
+
+Flags types are declared in your own crate by the `bitflags!` macro, so schemars can't implement
+[`JsonSchema`] for them directly. Instead, use the [`Flags`] marker type with
+`#[schemars(with = "...")]`:
+
+```
+# use bitflags2 as bitflags;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+bitflags::bitflags! {
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const EXECUTE = 1 << 2;
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct File {
+    #[schemars(with = "schemars::bitflags2::Flags<Permissions>")]
+    pub permissions: Permissions,
+}
+```
+*/
+
+use crate::_alloc_prelude::*;
+use crate::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+use core::any::type_name;
+use core::marker::PhantomData;
+
+/// Describes a `bitflags` type `T` in its human-readable serialized form, i.e. a string of flag
+/// names separated by `|` such as `"READ | WRITE"`.
+///
+/// The schema's `pattern` allows any combination of the named flags of `T`, as well as
+/// hexadecimal numbers such as `0x8`, which `bitflags` uses for bits that don't correspond to a
+/// named flag.
+#[derive(Debug)]
+pub struct Flags<T>(PhantomData<T>);
+
+impl<T: bitflags2::Flags> JsonSchema for Flags<T> {
+    fn schema_name() -> Cow<'static, str> {
+        let name = type_name::<T>();
+        name.rsplit("::").next().unwrap_or(name).to_owned().into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("bitflags::Flags<{}>", type_name::<T>()).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let flag = T::FLAGS
+            .iter()
+            .filter(|f| f.is_named())
+            .map(bitflags2::Flag::name)
+            .chain(["0x[0-9a-fA-F]+"])
+            .collect::<Vec<_>>()
+            .join("|");
+
+        json_schema!({
+            "type": "string",
+            "pattern": format!(r"^\s*(?:(?:{flag})\s*(?:\|\s*(?:{flag})\s*)*)?$"),
+        })
+    }
+}
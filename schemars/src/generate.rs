@@ -82,6 +82,10 @@ impl SchemaSettings {
                 Box::new(ReplaceUnevaluatedProperties),
                 Box::new(RemoveRefSiblings),
                 Box::new(ReplacePrefixItems),
+                Box::new(RemoveContainsCount),
+                Box::new(ReplaceDependentRequired {
+                    use_dependencies: true,
+                }),
             ],
             inline_subschemas: false,
             contract: Contract::Deserialize,
@@ -132,13 +136,56 @@ impl SchemaSettings {
                 }),
                 Box::new(SetSingleExample),
                 Box::new(ReplaceConstValue),
+                Box::new(ReplaceExclusiveBounds),
                 Box::new(ReplacePrefixItems),
+                Box::new(RemovePropertyNames),
+                Box::new(RemoveContains),
+                Box::new(ReplaceDependentRequired {
+                    use_dependencies: false,
+                }),
             ],
             inline_subschemas: false,
             contract: Contract::Deserialize,
         }
     }
 
+    /// Creates `SchemaSettings` that produce Kubernetes [structural schemas](https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#specifying-a-structural-schema),
+    /// for use in the `openAPIV3Schema` of a `CustomResourceDefinition`.
+    ///
+    /// These are based on [`SchemaSettings::openapi3()`], but inline all subschemas and also apply
+    /// the [`ReplaceNullType`] and [`KubernetesStructural`] transforms.
+    pub fn kubernetes() -> SchemaSettings {
+        SchemaSettings::openapi3().with(|s| {
+            s.meta_schema = None;
+            s.inline_subschemas = true;
+            s.transforms.push(Box::new(ReplaceNullType));
+            s.transforms.push(Box::new(KubernetesStructural));
+        })
+    }
+
+    /// Creates `SchemaSettings` that produce schemas for the "strict" mode of LLM structured
+    /// outputs and tool/function calling, e.g. when using `"strict": true` with
+    /// the `OpenAI` API.
+    ///
+    /// These schemas are a restricted subset of JSON Schema 2020-12 - see [`StrictMode`] for the
+    /// changes that are made. Note that the root schema must be an object schema, so these settings
+    /// should be used with structs rather than enums or primitive types.
+    ///
+    /// Some schemas cannot be converted to the strict subset, in which case the unsupported parts
+    /// are left in the generated schema. To detect these, generate a schema using settings without
+    /// the [`StrictMode`] transform and call [`StrictMode::try_transform`] on it instead.
+    pub fn llm_strict() -> SchemaSettings {
+        SchemaSettings {
+            option_nullable: false,
+            option_add_null_type: true,
+            definitions_path: "/$defs".to_owned(),
+            meta_schema: None,
+            transforms: vec![Box::new(StrictMode)],
+            inline_subschemas: false,
+            contract: Contract::Deserialize,
+        }
+    }
+
     /// Modifies the `SchemaSettings` by calling the given function.
     ///
     /// # Example
@@ -292,21 +339,7 @@ impl SchemaGenerator {
             let name = match self.schema_id_to_name.get(&uid).cloned() {
                 Some(n) => n,
                 None => {
-                    let base_name = T::schema_name();
-                    let mut name = CowStr::Borrowed("");
-
-                    if self.used_schema_names.contains(base_name.as_ref()) {
-                        for i in 2.. {
-                            name = format!("{base_name}{i}").into();
-                            if !self.used_schema_names.contains(&name) {
-                                break;
-                            }
-                        }
-                    } else {
-                        name = base_name;
-                    }
-
-                    self.used_schema_names.insert(name.clone());
+                    let name = self.reserve_schema_name(T::schema_name());
                     self.schema_id_to_name.insert(uid.clone(), name.clone());
                     name
                 }
@@ -322,6 +355,80 @@ impl SchemaGenerator {
         }
     }
 
+    fn reserve_schema_name(&mut self, base_name: CowStr) -> CowStr {
+        let mut name = CowStr::Borrowed("");
+
+        if self.used_schema_names.contains(base_name.as_ref()) {
+            for i in 2.. {
+                name = format!("{base_name}{i}").into();
+                if !self.used_schema_names.contains(&name) {
+                    break;
+                }
+            }
+        } else {
+            name = base_name;
+        }
+
+        self.used_schema_names.insert(name.clone());
+        name
+    }
+
+    /// Adds the given schema to this generator's definitions under a name based on `base_name`,
+    /// and returns a `$ref` schema referencing it. If an identical schema has already been added
+    /// with the same base name, then that definition is re-used.
+    ///
+    /// Transforms applied to the root schema only reach definitions at `$defs` or `definitions`,
+    /// so if the definitions path is elsewhere (e.g. `components/schemas` for OpenAPI 3.0), the
+    /// transforms are applied to `schema` before it is added.
+    pub(crate) fn insert_named_definition(
+        &mut self,
+        base_name: &str,
+        mut schema: Schema,
+    ) -> Schema {
+        if !matches!(self.definitions_path_stripped(), "/$defs" | "/definitions") {
+            self.apply_transforms(&mut schema);
+        }
+
+        let name = if self.definitions.get(base_name) == Some(schema.as_value()) {
+            CowStr::Owned(base_name.to_owned())
+        } else {
+            let name = self.reserve_schema_name(CowStr::Owned(base_name.to_owned()));
+            self.definitions.insert(name.to_string(), schema.to_value());
+            name
+        };
+
+        Schema::new_ref(format!("#{}/{}", self.definitions_path_stripped(), name))
+    }
+
+    /// Returns a `$ref` schema referencing the definition identified by `schema_id`, which is not
+    /// necessarily the id of a Rust type. If the definition does not exist yet, it is added under a
+    /// name based on `base_name`, using the schema returned by `json_schema`.
+    ///
+    /// Like [`subschema_for`](Self::subschema_for), this reserves the definition's name before
+    /// calling `json_schema`, so the schema may (recursively) reference itself.
+    pub(crate) fn definition_ref_for_id(
+        &mut self,
+        schema_id: CowStr,
+        base_name: CowStr,
+        json_schema: impl FnOnce(&mut Self) -> Schema,
+    ) -> Schema {
+        let uid = SchemaUid(schema_id, self.settings.contract.clone());
+        let name = match self.schema_id_to_name.get(&uid).cloned() {
+            Some(n) => n,
+            None => {
+                let name = self.reserve_schema_name(base_name);
+                self.schema_id_to_name.insert(uid, name.clone());
+                self.definitions.insert(name.clone().into(), false.into());
+                let schema = json_schema(self);
+                self.definitions
+                    .insert(name.clone().into(), schema.to_value());
+                name
+            }
+        };
+
+        Schema::new_ref(format!("#{}/{}", self.definitions_path_stripped(), name))
+    }
+
     fn insert_new_subschema_for<T: ?Sized + JsonSchema>(&mut self, name: CowStr, uid: SchemaUid) {
         // TODO: If we've already added a schema for T with the "opposite" contract, then check
         // whether the new schema is identical. If so, re-use the original for both contracts.
@@ -545,7 +652,7 @@ impl SchemaGenerator {
 
     /// Returns `self.settings.definitions_path` as a plain JSON pointer to the definitions object,
     /// i.e. without a leading '#' or trailing '/'
-    fn definitions_path_stripped(&self) -> &str {
+    pub(crate) fn definitions_path_stripped(&self) -> &str {
         let path = &self.settings.definitions_path;
         let path = path.strip_prefix('#').unwrap_or(path);
         path.strip_suffix('/').unwrap_or(path)
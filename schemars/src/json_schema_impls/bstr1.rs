@@ -0,0 +1,33 @@
+use crate::_alloc_prelude::*;
+use crate::generate::Contract;
+use crate::{JsonSchema, Schema};
+use alloc::borrow::Cow;
+use serde_json::Value;
+
+impl JsonSchema for bstr1::BString {
+    fn schema_name() -> Cow<'static, str> {
+        "BString".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "bstr::BString".into()
+    }
+
+    fn json_schema(generator: &mut crate::SchemaGenerator) -> crate::Schema {
+        let ty = match generator.contract() {
+            Contract::Deserialize => Value::Array(vec!["array".into(), "string".into()]),
+            Contract::Serialize => "array".into(),
+        };
+
+        let mut result = Schema::default();
+        result.insert(
+            "description".to_owned(),
+            "A byte string, which may contain non-UTF-8 bytes".into(),
+        );
+        result.insert("type".to_owned(), ty);
+        result.insert("items".to_owned(), generator.subschema_for::<u8>().into());
+        result
+    }
+}
+
+forward_impl!(bstr1::BStr => bstr1::BString);
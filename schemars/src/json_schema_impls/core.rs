@@ -17,49 +17,8 @@ impl<T: JsonSchema> JsonSchema for Option<T> {
     }
 
     fn json_schema(generator: &mut SchemaGenerator) -> Schema {
-        let mut schema = generator.subschema_for::<T>();
-
-        if generator.settings().option_add_null_type {
-            schema = match schema.try_to_object() {
-                Ok(mut obj) => {
-                    let instance_type = obj.get_mut("type");
-                    match instance_type {
-                        Some(Value::Array(array)) => {
-                            let null = Value::from("null");
-                            if !array.contains(&null) {
-                                array.push(null);
-                            }
-                            obj.into()
-                        }
-                        Some(Value::String(string)) => {
-                            if string != "null" {
-                                *instance_type.unwrap() = Value::Array(vec![
-                                    core::mem::take(string).into(),
-                                    "null".into(),
-                                ]);
-                            }
-                            obj.into()
-                        }
-                        _ => json_schema!({
-                            "anyOf": [
-                                obj,
-                                <()>::json_schema(generator)
-                            ]
-                        }),
-                    }
-                }
-                Err(true) => true.into(),
-                Err(false) => <()>::json_schema(generator),
-            }
-        }
-
-        if generator.settings().option_nullable {
-            schema
-                .ensure_object()
-                .insert("nullable".into(), true.into());
-        };
-
-        schema
+        let schema = generator.subschema_for::<T>();
+        make_nullable(generator, schema)
     }
 
     fn _schemars_private_non_optional_json_schema(generator: &mut SchemaGenerator) -> Schema {
@@ -71,6 +30,50 @@ impl<T: JsonSchema> JsonSchema for Option<T> {
     }
 }
 
+/// Modifies the given schema to also allow `null`, in the same way as the schema for `Option<T>`
+/// depending on the generator's settings.
+pub(crate) fn make_nullable(generator: &mut SchemaGenerator, mut schema: Schema) -> Schema {
+    if generator.settings().option_add_null_type {
+        schema = match schema.try_to_object() {
+            Ok(mut obj) => {
+                let instance_type = obj.get_mut("type");
+                match instance_type {
+                    Some(Value::Array(array)) => {
+                        let null = Value::from("null");
+                        if !array.contains(&null) {
+                            array.push(null);
+                        }
+                        obj.into()
+                    }
+                    Some(Value::String(string)) => {
+                        if string != "null" {
+                            *instance_type.unwrap() =
+                                Value::Array(vec![core::mem::take(string).into(), "null".into()]);
+                        }
+                        obj.into()
+                    }
+                    _ => json_schema!({
+                        "anyOf": [
+                            obj,
+                            <()>::json_schema(generator)
+                        ]
+                    }),
+                }
+            }
+            Err(true) => true.into(),
+            Err(false) => <()>::json_schema(generator),
+        }
+    }
+
+    if generator.settings().option_nullable {
+        schema
+            .ensure_object()
+            .insert("nullable".into(), true.into());
+    };
+
+    schema
+}
+
 impl<T: JsonSchema, E: JsonSchema> JsonSchema for Result<T, E> {
     fn schema_name() -> Cow<'static, str> {
         format!("Result_of_{}_or_{}", T::schema_name(), E::schema_name()).into()
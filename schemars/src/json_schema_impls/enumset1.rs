@@ -0,0 +1,39 @@
+use crate::_alloc_prelude::*;
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use enumset1::{EnumSet, EnumSetType};
+
+// By default, `EnumSet` is serialized as an integer bitset, or as an array of variants when the
+// enum has `#[enumset(serialize_repr = "list")]`. The schema can't tell which one is used, so it
+// allows both. The rarely-used "array" and "map" representations are not described.
+impl<T> JsonSchema for EnumSet<T>
+where
+    T: EnumSetType + JsonSchema,
+{
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("EnumSet_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("enumset::EnumSet<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "array",
+                    "uniqueItems": true,
+                    "items": generator.subschema_for::<T>(),
+                },
+                {
+                    "type": "integer",
+                    "minimum": 0,
+                }
+            ]
+        })
+    }
+}
@@ -0,0 +1,6 @@
+use crate::JsonSchema;
+use alloc::collections::{BTreeMap, BTreeSet};
+use hashbrown016::{HashMap, HashSet};
+
+forward_impl!((<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S>) => BTreeMap<K, V>);
+forward_impl!((<T: JsonSchema, S> JsonSchema for HashSet<T, S>) => BTreeSet<T>);
@@ -0,0 +1,27 @@
+use crate::_alloc_prelude::*;
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use heapless08::{String, Vec};
+
+// Do not set maxLength on the schema as that describes length in characters, but we only
+// know max length in bytes.
+forward_impl!((<const N: usize> JsonSchema for String<N>) => alloc::string::String);
+
+impl<T, const N: usize> JsonSchema for Vec<T, N>
+where
+    T: JsonSchema,
+{
+    always_inline!();
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        format!("Array_up_to_size_{}_of_{}", N, T::schema_name()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<T>(),
+            "maxItems": N
+        })
+    }
+}
@@ -0,0 +1,136 @@
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use http1::{HeaderMap, Method, StatusCode, Uri, Version};
+
+// `http` types do not implement serde's traits, so these schemas describe the representations used
+// by the `http-serde` crate.
+
+impl JsonSchema for StatusCode {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "StatusCode".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "http::StatusCode".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "format": "uint16",
+            "minimum": 100,
+            "maximum": 999,
+        })
+    }
+}
+
+impl JsonSchema for Method {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "Method".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "http::Method".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "anyOf": [
+                {
+                    "enum": [
+                        "GET",
+                        "HEAD",
+                        "POST",
+                        "PUT",
+                        "DELETE",
+                        "CONNECT",
+                        "OPTIONS",
+                        "TRACE",
+                        "PATCH",
+                    ]
+                },
+                {
+                    "description": "An extension method",
+                    "pattern": r"^[!#$%&'*+.^_`|~0-9A-Za-z-]+$",
+                }
+            ]
+        })
+    }
+}
+
+impl JsonSchema for Uri {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "Uri".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "http::Uri".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "uri-reference",
+        })
+    }
+}
+
+impl JsonSchema for Version {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "Version".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "http::Version".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "enum": ["HTTP/0.9", "HTTP/1.0", "HTTP/1.1", "HTTP/2.0", "HTTP/3.0"],
+        })
+    }
+}
+
+impl JsonSchema for HeaderMap {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "HeaderMap".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "http::HeaderMap".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        // Headers with a single value are serialized as a string, and headers with multiple values
+        // as an array of strings. Header values may not contain control characters other than tab.
+        let header_value = json_schema!({
+            "type": "string",
+            "pattern": r"^[^\x00-\x08\x0A-\x1F\x7F]*$",
+        });
+        json_schema!({
+            "type": "object",
+            "additionalProperties": {
+                "anyOf": [
+                    header_value,
+                    {
+                        "type": "array",
+                        "items": header_value,
+                    }
+                ]
+            },
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use crate::JsonSchema;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use im15::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+
+forward_impl!((<T: JsonSchema> JsonSchema for Vector<T>) => Vec<T>);
+forward_impl!((<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S>) => BTreeMap<K, V>);
+forward_impl!((<T: JsonSchema, S> JsonSchema for HashSet<T, S>) => BTreeSet<T>);
+forward_impl!((<K, V: JsonSchema> JsonSchema for OrdMap<K, V>) => BTreeMap<K, V>);
+forward_impl!((<T: JsonSchema> JsonSchema for OrdSet<T>) => BTreeSet<T>);
@@ -0,0 +1,54 @@
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use jiff02::civil::{Date, DateTime, Time};
+use jiff02::{SignedDuration, Span, Timestamp, Zoned};
+
+macro_rules! jiff_string_impl {
+    ($ty:ident, $id:literal, $($k:literal : $v:expr),+) => {
+        impl JsonSchema for $ty {
+            always_inline!();
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($ty).into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                $id.into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "string",
+                    $($k: $v,)+
+                })
+            }
+        }
+    };
+}
+
+const ISO_DURATION_PATTERN: &str =
+    r"^[+-]?P(\d+Y)?(\d+M)?(\d+W)?(\d+D)?(T(\d+(\.\d+)?H)?(\d+(\.\d+)?M)?(\d+(\.\d+)?S)?)?$";
+
+jiff_string_impl!(Timestamp, "jiff::Timestamp", "format": "date-time");
+jiff_string_impl!(
+    Zoned,
+    "jiff::Zoned",
+    "pattern": r"^([+-]\d{6}|\d{4})-\d{2}-\d{2}T\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:\d{2}(:\d{2})?)?(\[!?[^\]]+\])+$",
+    "description": "An RFC 9557 date-time with a bracketed time zone, e.g. \"2024-06-19T15:22:45-04:00[America/New_York]\""
+);
+jiff_string_impl!(Date, "jiff::civil::Date", "format": "date");
+jiff_string_impl!(Time, "jiff::civil::Time", "format": "partial-time");
+jiff_string_impl!(DateTime, "jiff::civil::DateTime", "format": "partial-date-time");
+jiff_string_impl!(
+    Span,
+    "jiff::Span",
+    "pattern": ISO_DURATION_PATTERN,
+    "description": "An ISO 8601 duration with an optional sign, e.g. \"P1Y2M3DT4H\""
+);
+jiff_string_impl!(
+    SignedDuration,
+    "jiff::SignedDuration",
+    "pattern": ISO_DURATION_PATTERN,
+    "description": "An ISO 8601 duration in hours, minutes and seconds with an optional sign, e.g. \"PT1H30M\""
+);
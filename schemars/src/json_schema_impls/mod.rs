@@ -50,30 +50,76 @@ mod std_time;
 mod tuple;
 mod wrapper;
 
+pub(crate) use self::core::make_nullable;
+
 #[cfg(target_has_atomic)]
 mod atomic;
 
 #[cfg(feature = "std")]
 mod ffi;
 
+#[cfg(feature = "arcstr1")]
+forward_impl!(arcstr1::ArcStr => alloc::string::String);
+
 #[cfg(feature = "arrayvec07")]
 mod arrayvec07;
 
+#[cfg(feature = "bstr1")]
+mod bstr1;
+
 #[cfg(feature = "bytes1")]
 mod bytes1;
 
+#[cfg(feature = "camino1")]
+forward_impl!(camino1::Utf8PathBuf => alloc::string::String);
+#[cfg(feature = "camino1")]
+forward_impl!(camino1::Utf8Path => str);
+
 #[cfg(feature = "chrono04")]
 mod chrono04;
 
+#[cfg(feature = "compact_str09")]
+forward_impl!(compact_str09::CompactString => alloc::string::String);
+
 #[cfg(any(feature = "rust_decimal1", feature = "bigdecimal04"))]
 mod decimal;
 
+#[cfg(feature = "ecow02")]
+forward_impl!(ecow02::EcoString => alloc::string::String);
+
 #[cfg(feature = "either1")]
 mod either1;
 
+#[cfg(feature = "enumset1")]
+mod enumset1;
+
+#[cfg(feature = "hashbrown016")]
+mod hashbrown016;
+
+#[cfg(feature = "heapless08")]
+mod heapless08;
+
+#[cfg(feature = "http1")]
+mod http1;
+
+#[cfg(feature = "im15")]
+mod im15;
+
 #[cfg(feature = "indexmap2")]
 mod indexmap2;
 
+#[cfg(feature = "jiff02")]
+mod jiff02;
+
+#[cfg(feature = "num_bigint04")]
+mod num_bigint04;
+
+#[cfg(feature = "num_rational04")]
+forward_impl!((<T: crate::JsonSchema> crate::JsonSchema for num_rational04::Ratio<T>) => (T, T));
+
+#[cfg(feature = "ordered_float5")]
+mod ordered_float5;
+
 #[cfg(feature = "semver1")]
 mod semver1;
 
@@ -83,6 +129,15 @@ forward_impl!((<A: smallvec1::Array> crate::JsonSchema for smallvec1::SmallVec<A
 #[cfg(feature = "smol_str02")]
 forward_impl!(smol_str02::SmolStr => alloc::string::String);
 
+#[cfg(feature = "thin_vec02")]
+forward_impl!((<T: crate::JsonSchema> crate::JsonSchema for thin_vec02::ThinVec<T>) => alloc::vec::Vec<T>);
+
+#[cfg(feature = "time03")]
+mod time03;
+
+#[cfg(feature = "ulid1")]
+mod ulid1;
+
 #[cfg(feature = "url2")]
 mod url2;
 
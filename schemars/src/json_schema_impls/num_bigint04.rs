@@ -0,0 +1,57 @@
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use num_bigint04::{BigInt, BigUint};
+
+// `num-bigint` serializes numbers as sequences of their base 2^32 "digits", least significant first.
+
+impl JsonSchema for BigUint {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "BigUint".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "num_bigint::BigUint".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "An unsigned integer, as an array of its base 2^32 digits in little-endian order",
+            "type": "array",
+            "items": u32::json_schema(generator),
+        })
+    }
+}
+
+impl JsonSchema for BigInt {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "BigInt".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "num_bigint::BigInt".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A signed integer, as its sign (-1, 0 or 1) followed by an array of its magnitude's base 2^32 digits in little-endian order",
+            "type": "array",
+            "prefixItems": [
+                {
+                    "type": "integer",
+                    "enum": [-1, 0, 1],
+                },
+                {
+                    "type": "array",
+                    "items": u32::json_schema(generator),
+                }
+            ],
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use crate::SchemaGenerator;
+use crate::_alloc_prelude::*;
+use crate::{JsonSchema, Schema};
+use alloc::borrow::Cow;
+use ordered_float5::{NotNan, OrderedFloat};
+
+forward_impl!((<T: JsonSchema> JsonSchema for OrderedFloat<T>) => T);
+
+impl<T: JsonSchema> JsonSchema for NotNan<T> {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("NotNan_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("ordered_float::NotNan<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        // JSON cannot represent NaN anyway, so this only adds a description
+        let mut schema = T::json_schema(generator);
+        schema.insert(
+            "description".to_owned(),
+            "A floating-point number which is not NaN".into(),
+        );
+        schema
+    }
+}
@@ -0,0 +1,46 @@
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use time03::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+// These schemas describe the string representations used by `time`'s `serde-human-readable`
+// feature, which differ slightly from RFC 3339 for types that include a time.
+macro_rules! time_string_impl {
+    ($ty:ident, $($k:literal : $v:expr),+) => {
+        impl JsonSchema for $ty {
+            always_inline!();
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($ty).into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                stringify!(time::$ty).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "string",
+                    $($k: $v,)+
+                })
+            }
+        }
+    };
+}
+
+time_string_impl!(Date, "format": "date");
+time_string_impl!(Time, "format": "partial-time");
+time_string_impl!(
+    PrimitiveDateTime,
+    "pattern": r"^[+-]?\d{4,6}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d+$"
+);
+time_string_impl!(
+    OffsetDateTime,
+    "pattern": r"^[+-]?\d{4,6}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d+ [+-]\d{2}:\d{2}:\d{2}$"
+);
+time_string_impl!(UtcOffset, "pattern": r"^[+-]\d{2}:\d{2}:\d{2}$");
+time_string_impl!(
+    Duration,
+    "pattern": r"^-?\d+\.\d{9}$",
+    "description": "A duration in seconds, with exactly 9 decimal places"
+);
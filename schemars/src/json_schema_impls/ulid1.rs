@@ -0,0 +1,24 @@
+use crate::SchemaGenerator;
+use crate::{json_schema, JsonSchema, Schema};
+use alloc::borrow::Cow;
+use ulid1::Ulid;
+
+impl JsonSchema for Ulid {
+    always_inline!();
+
+    fn schema_name() -> Cow<'static, str> {
+        "Ulid".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ulid::Ulid".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        // Crockford's base32 alphabet, which is case-insensitive when parsing
+        json_schema!({
+            "type": "string",
+            "pattern": "^[0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{26}$",
+        })
+    }
+}
@@ -30,7 +30,14 @@ mod macros;
 /// outside of `schemars`, and should not be considered part of the public API.
 #[doc(hidden)]
 pub mod _private;
+#[cfg(feature = "bitflags2")]
+pub mod bitflags2;
 pub mod generate;
+pub mod map;
+pub mod openapi;
+pub mod patch;
+#[cfg(feature = "time03")]
+pub mod time03;
 pub mod transform;
 
 #[cfg(feature = "schemars_derive")]
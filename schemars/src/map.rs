@@ -0,0 +1,154 @@
+/*!
+Marker type for describing the keys of map fields.
+
+The [`JsonSchema`] implementations for maps such as `BTreeMap<K, V>` and `HashMap<K, V>` allow
+any string as a property name, so they don't require `K` to implement `JsonSchema`. To also
+describe the map's keys using a `propertyNames` schema, use the [`KeyedMap`] marker type with
+`#[schemars(with = "...")]`:
+
+```
+use schemars::JsonSchema;
+use schemars::map::KeyedMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct Palette {
+    #[schemars(with = "KeyedMap<Color, String>")]
+    pub names: BTreeMap<Color, String>,
+    #[schemars(with = "KeyedMap<u32, String>")]
+    pub by_index: BTreeMap<u32, String>,
+}
+```
+*/
+
+use crate::_alloc_prelude::*;
+use crate::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+use core::marker::PhantomData;
+use serde_json::{Map, Value};
+
+/// Describes a map with keys of type `K` and values of type `V`, including a `propertyNames`
+/// schema for the keys when they are more constrained than an arbitrary string.
+///
+/// Keys get a `propertyNames` schema when `K` is a string type with additional constraints (e.g.
+/// a `format`), a unit-only enum, or an integer. Integer keys are described by a `pattern`, which
+/// distinguishes signed and unsigned integers but does not check their width, so e.g. `"300"` is
+/// allowed for `u8` keys.
+#[derive(Debug)]
+pub struct KeyedMap<K: ?Sized, V: ?Sized>(PhantomData<K>, PhantomData<V>);
+
+impl<K, V> JsonSchema for KeyedMap<K, V>
+where
+    K: ?Sized + JsonSchema,
+    V: ?Sized + JsonSchema,
+{
+    fn always_inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Map_of_{}", V::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("Map<{}, {}>", K::schema_id(), V::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = json_schema!({
+            "type": "object",
+        });
+        if let Some(property_names) = property_names_schema::<K>(generator) {
+            schema.insert("propertyNames".into(), property_names.to_value());
+        }
+        schema.insert(
+            "additionalProperties".into(),
+            generator.subschema_for::<V>().to_value(),
+        );
+        schema
+    }
+}
+
+// Converts the schema for a map's key type into a schema for the map's property names (which are
+// always strings in JSON), or returns `None` if the keys may be any string.
+fn property_names_schema<K: ?Sized + JsonSchema>(
+    generator: &mut SchemaGenerator,
+) -> Option<Schema> {
+    // Inspect the key schema using a separate generator, so that definitions are only added to
+    // `generator` if the key schema is actually used.
+    let mut scratch = SchemaGenerator::new(generator.settings().clone());
+    let key_schema = K::json_schema(&mut scratch);
+    let resolved = resolve_ref(&scratch, key_schema.as_object()?)?;
+
+    if is_string_schema(resolved) {
+        let is_constrained = resolved
+            .keys()
+            .any(|k| !matches!(k.as_str(), "type" | "title" | "description"));
+        return is_constrained.then(|| generator.subschema_for::<K>());
+    }
+
+    if resolved.get("type").and_then(Value::as_str) == Some("integer") {
+        let is_unsigned = resolved
+            .get("minimum")
+            .and_then(Value::as_f64)
+            .is_some_and(|m| m >= 0.0);
+        let pattern = if is_unsigned {
+            "^(0|[1-9][0-9]*)$"
+        } else {
+            "^(0|-?[1-9][0-9]*)$"
+        };
+        return Some(json_schema!({
+            "type": "string",
+            "pattern": pattern,
+        }));
+    }
+
+    None
+}
+
+fn resolve_ref<'a>(
+    generator: &'a SchemaGenerator,
+    schema: &'a Map<String, Value>,
+) -> Option<&'a Map<String, Value>> {
+    let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+        return Some(schema);
+    };
+
+    let prefix = format!("#{}/", generator.definitions_path_stripped());
+    let name = reference.strip_prefix(&prefix)?;
+    generator.definitions().get(name)?.as_object()
+}
+
+fn is_string_schema(schema: &Map<String, Value>) -> bool {
+    if let Some(instance_type) = schema.get("type") {
+        return instance_type == "string";
+    }
+
+    if let Some(value) = schema.get("const") {
+        return value.is_string();
+    }
+
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        return values.iter().all(Value::is_string);
+    }
+
+    ["oneOf", "anyOf"].into_iter().any(|keyword| {
+        schema
+            .get(keyword)
+            .and_then(Value::as_array)
+            .is_some_and(|subschemas| {
+                subschemas
+                    .iter()
+                    .all(|s| s.as_object().is_some_and(is_string_schema))
+            })
+    })
+}
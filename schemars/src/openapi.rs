@@ -0,0 +1,270 @@
+/*!
+Helpers for describing Rust types in [OpenAPI](https://spec.openapis.org/oas/v3.0.3) documents.
+
+JSON Schemas can be used directly as OpenAPI request/response bodies, but some parts of an OpenAPI
+document (such as [`parameters`](https://spec.openapis.org/oas/v3.0.3#parameter-object)) instead
+need a type's schema to be split into several objects.
+*/
+
+use crate::_alloc_prelude::*;
+use crate::{JsonSchema, Schema, SchemaGenerator};
+use core::fmt;
+use serde_json::{Map, Value};
+
+/// The location of an OpenAPI parameter, used as the value of its `in` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum ParameterLocation {
+    Query,
+    Header,
+    Path,
+    Cookie,
+}
+
+impl ParameterLocation {
+    /// Returns the name of this location as it appears in an OpenAPI document, e.g. `"query"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ParameterLocation::Query => "query",
+            ParameterLocation::Header => "header",
+            ParameterLocation::Path => "path",
+            ParameterLocation::Cookie => "cookie",
+        }
+    }
+}
+
+/// An error returned when a type's schema cannot be converted into OpenAPI parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParameterError {
+    /// The type's schema is not a plain object schema with `properties`, e.g. because it is an
+    /// enum or contains a flattened enum.
+    NotAnObject,
+    /// The schema of the named property is not a scalar (or array of scalars), so it cannot be
+    /// represented as a single parameter.
+    NonScalarProperty(String),
+    /// The named property is not required, but it would be a `path` parameter, which must always
+    /// be required.
+    OptionalPathParameter(String),
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterError::NotAnObject => {
+                f.write_str("schema is not an object schema with only `properties`")
+            }
+            ParameterError::NonScalarProperty(name) => write!(
+                f,
+                "property `{name}` is not a scalar or array of scalars, so cannot be a parameter"
+            ),
+            ParameterError::OptionalPathParameter(name) => {
+                write!(f, "path parameter `{name}` must be required")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParameterError {}
+
+/// Generates a list of OpenAPI [parameter objects](https://spec.openapis.org/oas/v3.0.3#parameter-object)
+/// for the type `T`, which should be a struct that is deserialized from e.g. a query string.
+///
+/// Each property of `T`'s schema becomes a separate parameter with its own `schema`, which is
+/// `required` if the property was required by `T`'s schema. Any `description` or `deprecated`
+/// keyword on a property's schema is moved to the parameter object.
+///
+/// If any property's schema depends on any [non-inlined](JsonSchema::always_inline_schema)
+/// schemas, then they are added to the `SchemaGenerator`'s definitions, and the parameter's
+/// `schema` will contain a `$ref` to them. The generator's [transforms](crate::generate::SchemaSettings::transforms)
+/// are applied to each parameter's `schema`.
+///
+/// This returns an error if `T`'s schema is not a plain object schema, or if any of its properties
+/// are not scalars (or arrays of scalars), e.g. nested structs or maps.
+///
+/// # Example
+/// ```
+/// use schemars::{generate::SchemaSettings, openapi::{parameters_for, ParameterLocation}, JsonSchema};
+/// use serde_json::json;
+///
+/// #[derive(JsonSchema)]
+/// struct Pagination {
+///     /// The page number to return.
+///     page: u32,
+///     per_page: Option<u32>,
+/// }
+///
+/// let mut generator = SchemaSettings::openapi3().into_generator();
+/// let parameters = parameters_for::<Pagination>(&mut generator, ParameterLocation::Query).unwrap();
+///
+/// assert_eq!(
+///     parameters,
+///     vec![
+///         json!({
+///             "name": "page",
+///             "in": "query",
+///             "required": true,
+///             "description": "The page number to return.",
+///             "schema": {
+///                 "type": "integer",
+///                 "format": "uint32",
+///                 "minimum": 0
+///             }
+///         }),
+///         json!({
+///             "name": "per_page",
+///             "in": "query",
+///             "required": false,
+///             "schema": {
+///                 "type": "integer",
+///                 "format": "uint32",
+///                 "minimum": 0,
+///                 "nullable": true
+///             }
+///         }),
+///     ]
+/// );
+/// ```
+pub fn parameters_for<T: ?Sized + JsonSchema>(
+    generator: &mut SchemaGenerator,
+    location: ParameterLocation,
+) -> Result<Vec<Value>, ParameterError> {
+    let schema = T::json_schema(generator);
+    let mut object = schema
+        .try_to_object()
+        .map_err(|_| ParameterError::NotAnObject)?;
+
+    if object.get("type").and_then(Value::as_str) != Some("object")
+        || ["$ref", "allOf", "anyOf", "oneOf", "if", "patternProperties"]
+            .into_iter()
+            .any(|k| object.contains_key(k))
+    {
+        return Err(ParameterError::NotAnObject);
+    }
+
+    let required: Vec<Value> = match object.remove("required") {
+        Some(Value::Array(r)) => r,
+        _ => Vec::new(),
+    };
+    let properties = match object.remove("properties") {
+        Some(Value::Object(p)) => p,
+        None => Map::new(),
+        Some(_) => return Err(ParameterError::NotAnObject),
+    };
+
+    let mut result = Vec::with_capacity(properties.len());
+
+    for (name, property) in properties {
+        let mut property: Schema = property
+            .try_into()
+            .map_err(|_| ParameterError::NotAnObject)?;
+
+        if !is_scalar_or_array(generator, &property, true) {
+            return Err(ParameterError::NonScalarProperty(name));
+        }
+
+        let is_required = required.iter().any(|r| r.as_str() == Some(name.as_str()));
+        if location == ParameterLocation::Path && !is_required {
+            return Err(ParameterError::OptionalPathParameter(name));
+        }
+
+        let mut parameter = Map::new();
+        parameter.insert("name".to_owned(), name.into());
+        parameter.insert("in".to_owned(), location.as_str().into());
+        parameter.insert("required".to_owned(), is_required.into());
+
+        for key in ["description", "deprecated"] {
+            if let Some(value) = property.remove(key) {
+                parameter.insert(key.to_owned(), value);
+            }
+        }
+
+        for transform in generator.transforms_mut() {
+            transform.transform(&mut property);
+        }
+        parameter.insert("schema".to_owned(), property.to_value());
+
+        result.push(Value::Object(parameter));
+    }
+
+    Ok(result)
+}
+
+const SCALAR_TYPES: [&str; 5] = ["string", "number", "integer", "boolean", "null"];
+
+fn is_scalar_or_array(generator: &SchemaGenerator, schema: &Schema, allow_array: bool) -> bool {
+    let Some(obj) = schema.as_object() else {
+        // `true` allows objects, and `false` can't be satisfied by any parameter value
+        return false;
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+        let prefix = format!("#{}/", generator.definitions_path_stripped());
+        let Some(target) = reference
+            .strip_prefix(&prefix)
+            .and_then(|name| generator.definitions().get(name))
+            .and_then(|v| <&Schema>::try_from(v).ok())
+        else {
+            return false;
+        };
+        if !is_scalar_or_array(generator, target, allow_array) {
+            return false;
+        }
+    }
+
+    let mut constrained = obj.contains_key("$ref");
+
+    if let Some(ty) = obj.get("type") {
+        constrained = true;
+        let types: Vec<&Value> = match ty {
+            Value::Array(types) => types.iter().collect(),
+            ty => vec![ty],
+        };
+        for ty in types {
+            match ty.as_str() {
+                Some("array") if allow_array => {
+                    let Some(items) = obj.get("items").and_then(|i| <&Schema>::try_from(i).ok())
+                    else {
+                        return false;
+                    };
+                    if !is_scalar_or_array(generator, items, false) {
+                        return false;
+                    }
+                }
+                Some(t) if SCALAR_TYPES.contains(&t) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    for key in ["enum", "const"] {
+        if let Some(value) = obj.get(key) {
+            constrained = true;
+            let values = match value {
+                Value::Array(values) if key == "enum" => values.as_slice(),
+                value => core::slice::from_ref(value),
+            };
+            if values
+                .iter()
+                .any(|v| matches!(v, Value::Array(_) | Value::Object(_)))
+            {
+                return false;
+            }
+        }
+    }
+
+    for key in ["allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(subschemas)) = obj.get(key) {
+            constrained = true;
+            let all_scalar = subschemas.iter().all(|s| {
+                <&Schema>::try_from(s).is_ok_and(|s| is_scalar_or_array(generator, s, allow_array))
+            });
+            if !all_scalar {
+                return false;
+            }
+        }
+    }
+
+    constrained
+}
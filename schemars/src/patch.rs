@@ -0,0 +1,266 @@
+/*!
+Schemas for [JSON Merge Patch](https://datatracker.ietf.org/doc/html/rfc7396) documents.
+
+A merge patch describes changes to a JSON document using a document of the same "shape": each
+property in the patch replaces the corresponding property of the target, a `null` property removes
+it, and nested objects are patched recursively. Any other value (including arrays) replaces the
+target value entirely.
+*/
+
+use crate::_alloc_prelude::*;
+use crate::json_schema_impls::make_nullable;
+use crate::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+/// A [JSON Merge Patch](https://datatracker.ietf.org/doc/html/rfc7396) document for a value of type
+/// `T`.
+///
+/// The schema for `MergePatch<T>` is derived from `T`'s schema, but with every property optional
+/// and nullable (where `null` means "remove this property"). Properties whose schemas are objects
+/// are patched recursively, while all other values (including arrays) must be replaced whole.
+///
+/// When `T`'s schema references other object schemas (e.g. from nested structs), patch schemas are
+/// added for them alongside the original schemas, named by adding a `Patch` suffix to the original
+/// schema's name, e.g. `AddressPatch` for `Address`. As with any other schema name, a number is
+/// appended if the name is already in use (e.g. by a type that is actually named `AddressPatch`).
+/// The schema for `MergePatch<T>` is named in the same way.
+///
+/// This type (de)serializes as the patch document itself, so can also be used to receive merge
+/// patches, e.g. in the body of a `PATCH` request.
+///
+/// # Example
+/// ```
+/// use schemars::{json_schema, patch::MergePatch, schema_for, JsonSchema};
+///
+/// #[derive(JsonSchema)]
+/// struct User {
+///     name: String,
+///     email: Option<String>,
+///     tags: Vec<String>,
+/// }
+///
+/// let schema = schema_for!(MergePatch<User>);
+/// assert_eq!(
+///     schema,
+///     json_schema!({
+///         "$schema": "https://json-schema.org/draft/2020-12/schema",
+///         "title": "UserPatch",
+///         "type": "object",
+///         "properties": {
+///             "name": { "type": ["string", "null"] },
+///             "email": { "type": ["string", "null"] },
+///             "tags": {
+///                 "type": ["array", "null"],
+///                 "items": { "type": "string" }
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub struct MergePatch<T> {
+    patch: Value,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> MergePatch<T> {
+    /// Creates a `MergePatch` from the given patch document.
+    pub fn new(patch: Value) -> Self {
+        Self {
+            patch,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the patch document.
+    pub fn as_value(&self) -> &Value {
+        &self.patch
+    }
+
+    /// Converts the `MergePatch` into the patch document.
+    pub fn into_inner(self) -> Value {
+        self.patch
+    }
+}
+
+impl<T> Clone for MergePatch<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.patch.clone())
+    }
+}
+
+impl<T> PartialEq for MergePatch<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.patch == other.patch
+    }
+}
+
+impl<T> fmt::Debug for MergePatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MergePatch").field(&self.patch).finish()
+    }
+}
+
+impl<T> Serialize for MergePatch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.patch.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for MergePatch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for MergePatch<T> {
+    fn always_inline_schema() -> bool {
+        T::always_inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("{}Patch", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("MergePatch<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let schema = T::json_schema(generator);
+        patch_schema(generator, schema)
+    }
+}
+
+fn patch_schema(generator: &mut SchemaGenerator, schema: Schema) -> Schema {
+    let mut obj = match schema.try_to_object() {
+        Ok(obj) => obj,
+        Err(b) => return b.into(),
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+        if let Some(patch_reference) = patch_definition(generator, reference) {
+            obj.insert("$ref".to_owned(), patch_reference.into());
+        }
+        return obj.into();
+    }
+
+    // Patch the non-null subschema of an `Option<T>`
+    if let Some(Value::Array(any_of)) = obj.get_mut("anyOf") {
+        if let [a, b] = any_of.as_mut_slice() {
+            let non_null = match (is_null_schema(a), is_null_schema(b)) {
+                (false, true) => Some(a),
+                (true, false) => Some(b),
+                _ => None,
+            };
+            if let Some(subschema) = non_null {
+                if let Ok(s) = Schema::try_from(subschema.take()) {
+                    *subschema = patch_schema(generator, s).to_value();
+                }
+            }
+        }
+        return obj.into();
+    }
+
+    if !is_patchable_object(&obj) {
+        return obj.into();
+    }
+
+    obj.remove("required");
+
+    if let Some(Value::Object(properties)) = obj.get_mut("properties") {
+        for property in properties.values_mut() {
+            if let Ok(mut s) = Schema::try_from(property.take()) {
+                s.remove("default");
+                *property = patch_property(generator, s).to_value();
+            }
+        }
+    }
+
+    if let Some(Value::Object(pattern_properties)) = obj.get_mut("patternProperties") {
+        for property in pattern_properties.values_mut() {
+            if let Ok(s) = Schema::try_from(property.take()) {
+                *property = patch_property(generator, s).to_value();
+            }
+        }
+    }
+
+    if let Some(additional_properties @ Value::Object(_)) = obj.get_mut("additionalProperties") {
+        if let Ok(s) = Schema::try_from(additional_properties.take()) {
+            *additional_properties = patch_property(generator, s).to_value();
+        }
+    }
+
+    obj.into()
+}
+
+fn patch_property(generator: &mut SchemaGenerator, schema: Schema) -> Schema {
+    let schema = patch_schema(generator, schema);
+    if allows_null(&schema) {
+        schema
+    } else {
+        make_nullable(generator, schema)
+    }
+}
+
+// Adds a patch schema for the definition referenced by `reference` (if it is an object schema),
+// returning a reference to the patch schema. The patch schema is identified by the target's
+// reference, so it is only added once, and its name can't clash with any other definition.
+fn patch_definition(generator: &mut SchemaGenerator, reference: &str) -> Option<String> {
+    let prefix = format!("#{}/", generator.definitions_path_stripped());
+    let name = reference.strip_prefix(&prefix)?;
+
+    let target = Schema::try_from(generator.definitions().get(name)?.clone()).ok()?;
+    if !target.as_object().is_some_and(is_patchable_object) {
+        return None;
+    }
+
+    let patch_ref = generator.definition_ref_for_id(
+        format!("MergePatch<{reference}>").into(),
+        format!("{name}Patch").into(),
+        |generator| patch_schema(generator, target),
+    );
+
+    patch_ref.get("$ref")?.as_str().map(ToOwned::to_owned)
+}
+
+fn is_patchable_object(obj: &Map<String, Value>) -> bool {
+    let is_object_type = match obj.get("type") {
+        Some(Value::String(t)) => t == "object",
+        Some(Value::Array(types)) => {
+            types.iter().any(|t| t == "object")
+                && types.iter().all(|t| t == "object" || t == "null")
+        }
+        Some(_) => false,
+        None => obj.contains_key("properties"),
+    };
+
+    is_object_type
+        && ["allOf", "anyOf", "oneOf", "if", "not", "$ref"]
+            .into_iter()
+            .all(|k| !obj.contains_key(k))
+}
+
+fn is_null_schema(value: &Value) -> bool {
+    value.get("type").is_some_and(|t| t == "null")
+}
+
+fn allows_null(schema: &Schema) -> bool {
+    let Some(obj) = schema.as_object() else {
+        return schema.as_bool() == Some(true);
+    };
+
+    obj.get("nullable") == Some(&Value::Bool(true))
+        || match obj.get("type") {
+            Some(Value::String(t)) => t == "null",
+            Some(Value::Array(types)) => types.iter().any(|t| t == "null"),
+            _ => false,
+        }
+        || obj
+            .get("anyOf")
+            .and_then(Value::as_array)
+            .is_some_and(|any_of| any_of.iter().any(is_null_schema))
+}
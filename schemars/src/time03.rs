@@ -0,0 +1,108 @@
+/*!
+Marker types for [`time`](https://docs.rs/time/0.3) values serialized with its `serde` helper
+modules.
+
+The [`JsonSchema`] implementations for `time` types describe the strings produced by its
+`serde-human-readable` feature. When a field is instead serialized using one of the modules in
+[`time::serde`](https://docs.rs/time/0.3/time/serde/index.html), use the matching marker type with
+`#[schemars(with = "...")]`:
+
+```
+# use time03 as time;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct Event {
+    #[serde(with = "time::serde::rfc3339")]
+    #[schemars(with = "schemars::time03::Rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp::option")]
+    #[schemars(with = "Option<schemars::time03::UnixTimestamp>")]
+    pub deleted_at: Option<OffsetDateTime>,
+}
+```
+*/
+
+use crate::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+
+/// Describes an `OffsetDateTime` serialized with `time::serde::rfc3339`, i.e. a string with the
+/// `date-time` format.
+#[derive(Debug)]
+pub enum Rfc3339 {}
+
+/// Describes an `OffsetDateTime` serialized with `time::serde::iso8601`, i.e. an ISO 8601 string
+/// such as `"+002024-01-01T00:00:00.000000000Z"`.
+#[derive(Debug)]
+pub enum Iso8601 {}
+
+/// Describes an `OffsetDateTime` serialized with `time::serde::timestamp` (or any of its
+/// `milliseconds`/`microseconds`/`nanoseconds` submodules), i.e. an integer number of units since
+/// the Unix epoch.
+#[derive(Debug)]
+pub enum UnixTimestamp {}
+
+impl JsonSchema for Rfc3339 {
+    fn always_inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Rfc3339".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "time::serde::rfc3339".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date-time",
+        })
+    }
+}
+
+impl JsonSchema for Iso8601 {
+    fn always_inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Iso8601".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "time::serde::iso8601".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "An ISO 8601 date and time with a UTC offset",
+        })
+    }
+}
+
+impl JsonSchema for UnixTimestamp {
+    fn always_inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "UnixTimestamp".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "time::serde::timestamp".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "description": "A timestamp relative to the Unix epoch",
+        })
+    }
+}
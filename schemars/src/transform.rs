@@ -364,6 +364,107 @@ impl Transform for ReplaceConstValue {
     }
 }
 
+/// Replaces numeric `exclusiveMinimum`/`exclusiveMaximum` schema properties with the boolean form
+/// used in JSON Schema Draft 4, where they modify the `minimum`/`maximum` properties.
+/// This also applies to subschemas.
+///
+/// If a schema has both an inclusive and an exclusive bound, only the stricter one is kept.
+///
+/// This is useful for dialects of JSON Schema (e.g. OpenAPI 3.0) that only support the boolean
+/// form of `exclusiveMinimum`/`exclusiveMaximum`.
+#[derive(Debug, Clone)]
+pub struct ReplaceExclusiveBounds;
+
+impl Transform for ReplaceExclusiveBounds {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        if let Some(obj) = schema.as_object_mut() {
+            replace_exclusive_bound(obj, "exclusiveMinimum", "minimum", |ex, inc| ex >= inc);
+            replace_exclusive_bound(obj, "exclusiveMaximum", "maximum", |ex, inc| ex <= inc);
+        }
+    }
+}
+
+fn replace_exclusive_bound(
+    obj: &mut Map<String, Value>,
+    exclusive_key: &str,
+    inclusive_key: &str,
+    is_stricter: fn(f64, f64) -> bool,
+) {
+    // Leave the boolean form untouched
+    let Some(exclusive) = obj.get(exclusive_key).filter(|v| v.is_number()).cloned() else {
+        return;
+    };
+    obj.remove(exclusive_key);
+
+    let inclusive = obj.get(inclusive_key).and_then(Value::as_f64);
+    if let (Some(ex), Some(inc)) = (exclusive.as_f64(), inclusive) {
+        if !is_stricter(ex, inc) {
+            return;
+        }
+    }
+
+    obj.insert(inclusive_key.to_owned(), exclusive);
+    obj.insert(exclusive_key.to_owned(), true.into());
+}
+
+/// Removes the `propertyNames` schema property. This also applies to subschemas.
+///
+/// This is useful for dialects of JSON Schema (e.g. OpenAPI 3.0) that do not support the
+/// `propertyNames` property.
+#[derive(Debug, Clone)]
+pub struct RemovePropertyNames;
+
+impl Transform for RemovePropertyNames {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        schema.remove("propertyNames");
+    }
+}
+
+/// Removes the `minContains` and `maxContains` schema properties. This also applies to subschemas.
+///
+/// If `minContains` was 0 (i.e. the array was not required to contain any matching items), then
+/// the `contains` property is also removed, so that the schema does not become stricter.
+///
+/// This is useful for versions of JSON Schema (e.g. Draft 7) that do not support the
+/// `minContains` and `maxContains` properties.
+#[derive(Debug, Clone)]
+pub struct RemoveContainsCount;
+
+impl Transform for RemoveContainsCount {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        schema.remove("maxContains");
+        if let Some(min_contains) = schema.remove("minContains") {
+            if min_contains.as_u64() == Some(0) {
+                schema.remove("contains");
+            }
+        }
+    }
+}
+
+/// Removes the `contains`, `minContains` and `maxContains` schema properties. This also applies to
+/// subschemas.
+///
+/// This is useful for dialects of JSON Schema (e.g. OpenAPI 3.0) that do not support the
+/// `contains` property.
+#[derive(Debug, Clone)]
+pub struct RemoveContains;
+
+impl Transform for RemoveContains {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        schema.remove("contains");
+        schema.remove("minContains");
+        schema.remove("maxContains");
+    }
+}
+
 /// Rename the `prefixItems` schema property to `items`.
 /// This also applies to subschemas.
 ///
@@ -448,3 +549,683 @@ impl Transform for GatherPropertyNames {
         transform_immediate_subschemas(self, schema);
     }
 }
+
+/// Replaces the `dependentRequired` schema property with an equivalent `dependencies` property or
+/// subschemas in `allOf`. This also applies to subschemas.
+///
+/// This is useful for versions of JSON Schema (e.g. Draft 7 and OpenAPI 3.0) that do not support
+/// the `dependentRequired` property.
+#[derive(Debug, Clone)]
+pub struct ReplaceDependentRequired {
+    /// When set to `true`, `dependentRequired` is replaced with the `dependencies` property (whose
+    /// array form has the same meaning), as supported by Draft 7. Otherwise, each dependency is
+    /// replaced with an `anyOf` subschema that uses `not`, which is supported by dialects of JSON
+    /// Schema (e.g. OpenAPI 3.0) that do not support `dependencies`.
+    pub use_dependencies: bool,
+}
+
+impl Transform for ReplaceDependentRequired {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        let Some(Value::Object(dependent_required)) = schema.remove("dependentRequired") else {
+            return;
+        };
+
+        if self.use_dependencies {
+            if let Some(dependencies) = schema
+                .ensure_object()
+                .entry("dependencies")
+                .or_insert(Value::Object(Map::new()))
+                .as_object_mut()
+            {
+                for (key, required) in dependent_required {
+                    dependencies.entry(key).or_insert(required);
+                }
+            }
+            return;
+        }
+
+        let subschemas = dependent_required.into_iter().map(|(key, required)| {
+            json!({
+                "anyOf": [
+                    { "not": { "required": [key] } },
+                    { "required": required }
+                ]
+            })
+        });
+
+        if let Some(all_of) = schema
+            .ensure_object()
+            .entry("allOf")
+            .or_insert(Value::Array(Vec::new()))
+            .as_array_mut()
+        {
+            all_of.extend(subschemas);
+        }
+    }
+}
+
+/// Replaces `null` in a `type` array, or a `{"type": "null"}` subschema in an `anyOf`/`oneOf`, with
+/// the `nullable` property. This also applies to subschemas.
+///
+/// Schemas that only allow `null` are left unchanged, as they cannot be expressed using `nullable`.
+///
+/// This is useful for dialects of JSON Schema (e.g. OpenAPI 3.0) that do not support `null` as a
+/// type.
+#[derive(Debug, Clone)]
+pub struct ReplaceNullType;
+
+impl Transform for ReplaceNullType {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        let Some(obj) = schema.as_object_mut() else {
+            return;
+        };
+
+        if let Some(Value::Array(types)) = obj.get_mut("type") {
+            if types.iter().any(|t| t == "null") && types.iter().any(|t| t != "null") {
+                types.retain(|t| t != "null");
+                if types.len() == 1 {
+                    let ty = types.remove(0);
+                    obj.insert("type".to_owned(), ty);
+                }
+                obj.insert("nullable".to_owned(), true.into());
+            }
+        }
+
+        for keyword in ["anyOf", "oneOf"] {
+            let Some(Value::Array(subschemas)) = obj.get_mut(keyword) else {
+                continue;
+            };
+            let null_schema = json!({ "type": "null" });
+            if !subschemas.contains(&null_schema) || subschemas.iter().all(|s| s == &null_schema) {
+                continue;
+            }
+            subschemas.retain(|s| s != &null_schema);
+
+            obj.insert("nullable".to_owned(), true.into());
+
+            // Unwrap a single remaining subschema if it doesn't conflict with any other keywords
+            if let Some(Value::Array(subschemas)) = obj.get(keyword) {
+                if let [Value::Object(subschema)] = subschemas.as_slice() {
+                    if subschema
+                        .keys()
+                        .all(|k| k != keyword && !obj.contains_key(k))
+                    {
+                        let subschema = subschema.clone();
+                        obj.remove(keyword);
+                        obj.extend(subschema);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rewrites a schema into a Kubernetes [structural schema](https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#specifying-a-structural-schema),
+/// as required by `CustomResourceDefinition`s. This also applies to subschemas.
+///
+/// This should be used with settings based on OpenAPI 3.0 that inline all subschemas, such as
+/// [`SchemaSettings::kubernetes()`](crate::generate::SchemaSettings::kubernetes). It makes the
+/// following changes:
+/// - schemas that allow any value (e.g. for `serde_json::Value`) have
+///   `x-kubernetes-preserve-unknown-fields` set
+/// - `anyOf`/`oneOf` of an integer and a string schema is replaced with
+///   `x-kubernetes-int-or-string`
+/// - maps with arbitrary values, and objects with both `properties` and `additionalProperties`
+///   (e.g. from a flattened map) have `x-kubernetes-preserve-unknown-fields` set instead of
+///   `additionalProperties`
+/// - `additionalProperties: false` is removed, as Kubernetes always prunes unknown fields
+/// - `anyOf`/`oneOf` subschemas that are all objects (e.g. from an enum with only struct
+///   variants) have their `properties` moved to the parent schema, leaving only `required` and
+///   any property `enum`s in each subschema. Similarly, subschemas that are all string enums are merged into a single `enum`.
+/// - `discriminator` (e.g. from `#[schemars(discriminator)]`) is removed, as it is not supported by
+///   Kubernetes
+///
+/// Some schemas cannot be made structural (e.g. for recursive types, tuples, or enums with both
+/// unit and struct variants), in which case they are left unchanged and the schema will be rejected
+/// by the Kubernetes API server.
+#[derive(Debug, Clone)]
+pub struct KubernetesStructural;
+
+impl Transform for KubernetesStructural {
+    fn transform(&mut self, schema: &mut Schema) {
+        transform_subschemas(self, schema);
+
+        let Some(obj) = schema.as_object_mut() else {
+            return;
+        };
+
+        obj.remove("discriminator");
+
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(subschemas)) = obj.get(keyword) {
+                if is_int_or_string(subschemas) {
+                    obj.remove(keyword);
+                    obj.insert("x-kubernetes-int-or-string".to_owned(), true.into());
+                } else if let Some(merged) = merge_string_enums(subschemas) {
+                    obj.remove(keyword);
+                    obj.insert("type".to_owned(), "string".into());
+                    obj.insert("enum".to_owned(), merged.into());
+                } else if obj.get("type").map_or(true, |t| t == "object") {
+                    hoist_object_properties(obj, keyword);
+                }
+            }
+        }
+
+        if obj.get("type").and_then(Value::as_str) == Some("object") {
+            match obj.get("additionalProperties") {
+                Some(Value::Bool(false)) => {
+                    obj.remove("additionalProperties");
+                }
+                Some(Value::Bool(true)) => {
+                    obj.remove("additionalProperties");
+                    obj.insert(
+                        "x-kubernetes-preserve-unknown-fields".to_owned(),
+                        true.into(),
+                    );
+                }
+                Some(Value::Object(ap))
+                    if obj.contains_key("properties")
+                        || ap.contains_key("x-kubernetes-preserve-unknown-fields") =>
+                {
+                    obj.remove("additionalProperties");
+                    obj.insert(
+                        "x-kubernetes-preserve-unknown-fields".to_owned(),
+                        true.into(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let is_unconstrained = [
+            "type",
+            "properties",
+            "additionalProperties",
+            "items",
+            "enum",
+            "allOf",
+            "anyOf",
+            "oneOf",
+            "not",
+            "$ref",
+            "x-kubernetes-int-or-string",
+            "x-kubernetes-preserve-unknown-fields",
+        ]
+        .into_iter()
+        .all(|k| !obj.contains_key(k));
+        if is_unconstrained {
+            obj.insert(
+                "x-kubernetes-preserve-unknown-fields".to_owned(),
+                true.into(),
+            );
+        }
+    }
+}
+
+fn is_int_or_string(subschemas: &[Value]) -> bool {
+    let mut types: Vec<&str> = subschemas
+        .iter()
+        .filter_map(|s| s.get("type")?.as_str())
+        .collect();
+    types.sort_unstable();
+    subschemas.len() == 2 && types == ["integer", "string"]
+}
+
+fn merge_string_enums(subschemas: &[Value]) -> Option<Vec<Value>> {
+    let mut result = Vec::new();
+    for subschema in subschemas {
+        let obj = subschema.as_object()?;
+        if obj.get("type")? != "string"
+            || obj
+                .keys()
+                .any(|k| !["type", "enum", "description", "title"].contains(&k.as_str()))
+        {
+            return None;
+        }
+        result.extend(obj.get("enum")?.as_array()?.iter().cloned());
+    }
+    Some(result)
+}
+
+fn hoist_object_properties(obj: &mut Map<String, Value>, keyword: &str) {
+    let Some(Value::Array(subschemas)) = obj.get(keyword) else {
+        return;
+    };
+
+    let all_objects = subschemas.iter().all(|s| {
+        s.get("type").and_then(Value::as_str) == Some("object")
+            && s.as_object().is_some_and(|s| {
+                s.keys().all(|k| {
+                    [
+                        "type",
+                        "properties",
+                        "required",
+                        "additionalProperties",
+                        "description",
+                        "title",
+                    ]
+                    .contains(&k.as_str())
+                })
+            })
+    });
+    if !all_objects {
+        return;
+    }
+
+    let Some(Value::Array(subschemas)) = obj.remove(keyword) else {
+        return;
+    };
+
+    let mut properties = match obj.remove("properties") {
+        Some(Value::Object(p)) => p,
+        _ => Map::new(),
+    };
+    let mut branches = Vec::with_capacity(subschemas.len());
+    let mut all_required = true;
+
+    for mut subschema in subschemas {
+        // Only value validations are allowed within logical junctors, so keep any `enum`s (e.g.
+        // for an internally-tagged enum's tag) so that the subschemas can still be distinguished.
+        let mut branch_properties = Map::new();
+        if let Some(Value::Object(sub_properties)) = subschema.get_mut("properties") {
+            for (name, sub_property) in core::mem::take(sub_properties) {
+                if let Some(enum_values) = sub_property.get("enum") {
+                    branch_properties.insert(name.clone(), json!({ "enum": enum_values }));
+                }
+                merge_property(&mut properties, name, sub_property);
+            }
+        }
+        match subschema.get_mut("required") {
+            Some(Value::Array(required)) if !required.is_empty() => {
+                let mut branch = json!({ "required": core::mem::take(required) });
+                if !branch_properties.is_empty() {
+                    branch["properties"] = branch_properties.into();
+                }
+                branches.push(branch);
+            }
+            _ => all_required = false,
+        }
+    }
+
+    obj.insert("type".to_owned(), "object".into());
+    obj.insert("properties".to_owned(), properties.into());
+    // If any subschema has no required properties, then it can't be distinguished from the others
+    if all_required {
+        obj.insert(keyword.to_owned(), branches.into());
+    }
+}
+
+fn merge_property(properties: &mut Map<String, Value>, name: String, new: Value) {
+    let Some(existing) = properties.get_mut(&name) else {
+        properties.insert(name, new);
+        return;
+    };
+
+    // Merge the values of e.g. an internally-tagged enum's tag property
+    let existing_enum = existing.get("enum").and_then(Value::as_array);
+    let new_enum = new.get("enum").and_then(Value::as_array);
+    if let (Some(existing_enum), Some(new_enum)) = (existing_enum, new_enum) {
+        let mut merged = existing_enum.clone();
+        merged.extend(
+            new_enum
+                .iter()
+                .filter(|v| !existing_enum.contains(v))
+                .cloned(),
+        );
+        existing["enum"] = merged.into();
+    }
+}
+
+/// Rewrites a schema into the restricted subset of JSON Schema accepted by the "strict" mode of
+/// LLM structured-output and tool-calling APIs. This also applies to subschemas.
+///
+/// This makes the following changes:
+/// - every object schema has all of its properties listed in `required`, and `additionalProperties`
+///   set to `false`. Properties that were not required keep their existing schema, so an `Option`
+///   field can still be set to `null`, while a field with a default value must now be provided.
+/// - `nullable` is replaced with an equivalent `type` array or `anyOf` containing `null`
+/// - `oneOf` is replaced with `anyOf`
+/// - a `$ref` with sibling keywords is wrapped in a single-element `anyOf`
+/// - `format` is removed from non-string schemas (e.g. `"format": "uint8"`), and the annotation
+///   keywords `default`, `examples`, `deprecated`, `readOnly`, `writeOnly` and `$comment` are removed
+///
+/// Any other keyword that is not supported by strict mode (e.g. `allOf`, `prefixItems` or
+/// `patternProperties`), non-`false` `additionalProperties`, unsupported string `format`s and
+/// boolean subschemas are left in place, so that they are rejected by the API rather than silently
+/// dropped. To detect these before sending the schema, use [`StrictMode::try_transform`].
+#[derive(Debug, Clone)]
+pub struct StrictMode;
+
+impl StrictMode {
+    /// Applies the transform to the given [`Schema`], returning an error describing the first
+    /// part of the schema that could not be converted into the strict subset (if any).
+    ///
+    /// The conversion is still applied to all other parts of the schema when this returns an
+    /// error.
+    ///
+    /// # Example
+    /// ```
+    /// use schemars::{json_schema, transform::{StrictMode, StrictModeError}};
+    ///
+    /// let mut schema = json_schema!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "name": { "type": "string" },
+    ///         "age": { "type": ["integer", "null"], "format": "uint8", "minimum": 0 }
+    ///     },
+    ///     "required": ["name"]
+    /// });
+    /// assert_eq!(StrictMode.try_transform(&mut schema), Ok(()));
+    /// assert_eq!(
+    ///     schema,
+    ///     json_schema!({
+    ///         "type": "object",
+    ///         "properties": {
+    ///             "name": { "type": "string" },
+    ///             "age": { "type": ["integer", "null"], "minimum": 0 }
+    ///         },
+    ///         "required": ["name", "age"],
+    ///         "additionalProperties": false
+    ///     })
+    /// );
+    ///
+    /// let mut schema = json_schema!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "tags": { "type": "array", "items": { "type": "string" }, "uniqueItems": true }
+    ///     }
+    /// });
+    /// assert_eq!(
+    ///     StrictMode.try_transform(&mut schema),
+    ///     Err(StrictModeError::UnsupportedKeyword {
+    ///         pointer: "/properties/tags".to_owned(),
+    ///         keyword: "uniqueItems".to_owned(),
+    ///     })
+    /// );
+    /// ```
+    pub fn try_transform(&mut self, schema: &mut Schema) -> Result<(), StrictModeError> {
+        let mut errors = Vec::new();
+        strict_mode_subschema(schema, &mut String::new(), &mut errors);
+
+        if schema.get("type").and_then(Value::as_str) != Some("object")
+            || schema.get("anyOf").is_some()
+        {
+            errors.insert(0, StrictModeError::RootNotObject);
+        }
+
+        match errors.into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Transform for StrictMode {
+    fn transform(&mut self, schema: &mut Schema) {
+        let _ = self.try_transform(schema);
+    }
+}
+
+/// An error returned by [`StrictMode::try_transform`] for a schema that cannot be converted into
+/// the strict subset of JSON Schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrictModeError {
+    /// The root schema is not a plain object schema, e.g. because it is generated for an enum.
+    RootNotObject,
+    /// The subschema at the given JSON pointer is a boolean schema.
+    BooleanSchema {
+        /// JSON pointer to the subschema.
+        pointer: String,
+    },
+    /// The subschema at the given JSON pointer uses a keyword (or a keyword value) that is not
+    /// supported in strict mode.
+    UnsupportedKeyword {
+        /// JSON pointer to the subschema.
+        pointer: String,
+        /// The unsupported keyword.
+        keyword: String,
+    },
+}
+
+impl core::fmt::Display for StrictModeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StrictModeError::RootNotObject => f.write_str("root schema must be an object schema"),
+            StrictModeError::BooleanSchema { pointer } => {
+                write!(f, "boolean schema at '{pointer}' is not supported")
+            }
+            StrictModeError::UnsupportedKeyword { pointer, keyword } => {
+                write!(f, "keyword `{keyword}` at '{pointer}' is not supported")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrictModeError {}
+
+const STRICT_MODE_KEYWORDS: [&str; 23] = [
+    "$schema",
+    "$ref",
+    "$defs",
+    "definitions",
+    "title",
+    "description",
+    "type",
+    "enum",
+    "const",
+    "anyOf",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "pattern",
+    "format",
+    "multipleOf",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "minItems",
+    "maxItems",
+];
+
+const STRICT_MODE_STRING_FORMATS: [&str; 9] = [
+    "date-time",
+    "time",
+    "date",
+    "duration",
+    "email",
+    "hostname",
+    "ipv4",
+    "ipv6",
+    "uuid",
+];
+
+fn unsupported(pointer: &str, keyword: &str) -> StrictModeError {
+    StrictModeError::UnsupportedKeyword {
+        pointer: pointer.to_owned(),
+        keyword: keyword.to_owned(),
+    }
+}
+
+fn strict_mode_subschema(
+    schema: &mut Schema,
+    pointer: &mut String,
+    errors: &mut Vec<StrictModeError>,
+) {
+    let Some(obj) = schema.as_object_mut() else {
+        errors.push(StrictModeError::BooleanSchema {
+            pointer: pointer.clone(),
+        });
+        return;
+    };
+
+    for annotation in [
+        "default",
+        "examples",
+        "deprecated",
+        "readOnly",
+        "writeOnly",
+        "$comment",
+    ] {
+        obj.remove(annotation);
+    }
+
+    if obj.remove("nullable") == Some(Value::Bool(true)) {
+        match obj.get_mut("type") {
+            Some(Value::String(ty)) => {
+                let ty = core::mem::take(ty);
+                obj.insert("type".to_owned(), json!([ty, "null"]));
+            }
+            Some(Value::Array(types)) => {
+                if !types.iter().any(|t| t == "null") {
+                    types.push("null".into());
+                }
+            }
+            _ => {
+                let mut inner = Value::Object(core::mem::take(obj));
+                // Unwrap `{"allOf": [{"$ref": ...}]}` as generated by `RemoveRefSiblings`
+                if let Some(Value::Array(all_of)) = inner.get_mut("allOf") {
+                    if all_of.len() == 1 && inner.as_object().is_some_and(|o| o.len() == 1) {
+                        inner = inner["allOf"][0].take();
+                    }
+                }
+                obj.insert("anyOf".to_owned(), json!([inner, { "type": "null" }]));
+            }
+        }
+    }
+
+    if let Some(one_of) = obj.remove("oneOf") {
+        if obj.contains_key("anyOf") {
+            obj.insert("oneOf".to_owned(), one_of);
+        } else {
+            obj.insert("anyOf".to_owned(), one_of);
+        }
+    }
+
+    if obj.len() > 1 && obj.contains_key("$ref") {
+        let reference = obj.remove("$ref");
+        if obj.contains_key("anyOf") {
+            obj.insert("allOf".to_owned(), json!([{ "$ref": reference }]));
+        } else {
+            obj.insert("anyOf".to_owned(), json!([{ "$ref": reference }]));
+        }
+    }
+
+    match obj.get("format").and_then(Value::as_str) {
+        Some(_) if !strict_mode_has_type(obj, "string") => {
+            obj.remove("format");
+        }
+        Some(format) if !STRICT_MODE_STRING_FORMATS.contains(&format) => {
+            errors.push(unsupported(pointer, "format"));
+        }
+        _ => {}
+    }
+
+    if obj.contains_key("properties") || strict_mode_has_type(obj, "object") {
+        strict_mode_object(obj, pointer, errors);
+    }
+
+    for keyword in obj.keys() {
+        let is_supported = if keyword == "$schema" {
+            pointer.is_empty()
+        } else {
+            STRICT_MODE_KEYWORDS.contains(&keyword.as_str())
+        };
+        if !is_supported {
+            errors.push(unsupported(pointer, keyword));
+        }
+    }
+
+    strict_mode_subschemas(obj, pointer, errors);
+}
+
+fn strict_mode_has_type(obj: &Map<String, Value>, ty: &str) -> bool {
+    match obj.get("type") {
+        Some(Value::String(s)) => s == ty,
+        Some(Value::Array(a)) => a.iter().any(|t| t == ty),
+        _ => false,
+    }
+}
+
+fn strict_mode_object(
+    obj: &mut Map<String, Value>,
+    pointer: &str,
+    errors: &mut Vec<StrictModeError>,
+) {
+    if obj.get("unevaluatedProperties") == Some(&Value::Bool(false))
+        && !["allOf", "anyOf", "if", "$ref"]
+            .into_iter()
+            .any(|k| obj.contains_key(k))
+    {
+        obj.remove("unevaluatedProperties");
+    }
+
+    match obj
+        .entry("additionalProperties")
+        .or_insert(Value::Bool(false))
+    {
+        Value::Bool(false) => {}
+        _ => errors.push(unsupported(pointer, "additionalProperties")),
+    }
+
+    let property_names: Vec<Value> = obj
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|p| p.keys().cloned().map(Value::from).collect())
+        .unwrap_or_default();
+    obj.insert("required".to_owned(), property_names.into());
+}
+
+fn strict_mode_subschemas(
+    obj: &mut Map<String, Value>,
+    pointer: &mut String,
+    errors: &mut Vec<StrictModeError>,
+) {
+    let original_len = pointer.len();
+    for (key, value) in obj.iter_mut() {
+        match key.as_str() {
+            "items" | "additionalProperties" => {
+                if value.is_object() {
+                    pointer.push('/');
+                    pointer.push_str(key);
+                    if let Ok(subschema) = value.try_into() {
+                        strict_mode_subschema(subschema, pointer, errors);
+                    }
+                    pointer.truncate(original_len);
+                } else if key == "items" {
+                    errors.push(unsupported(pointer, "items"));
+                }
+            }
+            "anyOf" => {
+                for (i, value) in value.as_array_mut().into_iter().flatten().enumerate() {
+                    pointer.push_str("/anyOf/");
+                    pointer.push_str(&i.to_string());
+                    if let Ok(subschema) = value.try_into() {
+                        strict_mode_subschema(subschema, pointer, errors);
+                    }
+                    pointer.truncate(original_len);
+                }
+            }
+            "properties" | "$defs" | "definitions" => {
+                for (name, value) in value.as_object_mut().into_iter().flatten() {
+                    pointer.push('/');
+                    pointer.push_str(key);
+                    pointer.push('/');
+                    pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+                    if let Ok(subschema) = value.try_into() {
+                        strict_mode_subschema(subschema, pointer, errors);
+                    }
+                    pointer.truncate(original_len);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Aliased {
+    #[serde(alias = "old_name", alias = "legacy_name")]
+    name: String,
+    #[serde(rename = "count", alias = "total")]
+    number: i32,
+    #[serde(default, alias = "desc")]
+    description: Option<String>,
+}
+
+#[test]
+fn aliases() {
+    test!(Aliased)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([Aliased {
+            name: "a".to_owned(),
+            number: 1,
+            description: None,
+        }])
+        .assert_allows_de_roundtrip([
+            json!({ "name": "a", "count": 1 }),
+            json!({ "old_name": "a", "total": 1 }),
+            json!({ "legacy_name": "a", "count": 1, "desc": "d" }),
+            json!({ "name": "a", "count": 1, "description": "d" }),
+        ])
+        .assert_rejects_de([
+            json!({ "count": 1 }),
+            json!({ "name": "a" }),
+            json!({ "name": "a", "old_name": "b", "count": 1 }),
+            json!({ "name": "a", "count": 1, "unknown": true }),
+        ]);
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct AliasedAllowUnknownFields {
+    #[serde(alias = "old_value")]
+    value: bool,
+}
+
+#[test]
+fn aliases_allow_unknown_fields() {
+    test!(AliasedAllowUnknownFields)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([AliasedAllowUnknownFields { value: true }])
+        .assert_allows_de_roundtrip([
+            json!({ "value": true }),
+            json!({ "old_value": false }),
+            json!({ "value": true, "other": 1 }),
+        ])
+        .assert_rejects_de([json!({}), json!({ "old_value": 1 })]);
+}
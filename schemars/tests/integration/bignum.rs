@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+#[cfg(feature = "num_bigint04")]
+fn has_non_u32_digit(value: &Value) -> bool {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|v| v.as_u64().is_some_and(|u| u > u32::MAX.into()))
+}
+
+#[test]
+#[cfg(feature = "num_bigint04")]
+fn biguint() {
+    use num_bigint04::BigUint;
+
+    test!(BigUint)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            BigUint::default(),
+            BigUint::from(u32::MAX),
+            BigUint::from(u128::MAX),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            has_non_u32_digit,
+            "u32's schema doesn't include a maximum",
+        ));
+}
+
+#[test]
+#[cfg(feature = "num_bigint04")]
+fn bigint() {
+    use num_bigint04::BigInt;
+
+    test!(BigInt)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            BigInt::default(),
+            BigInt::from(i64::MIN),
+            BigInt::from(u128::MAX),
+        ])
+        .assert_rejects_de([json!([1]), json!([1, [1], 1])])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+#[cfg(feature = "num_rational04")]
+fn ratio() {
+    use num_rational04::Ratio;
+
+    test!(Ratio<i32>)
+        .assert_identical::<(i32, i32)>()
+        .assert_allows_ser_roundtrip([Ratio::new(1, 3), Ratio::new(-6, 4)])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
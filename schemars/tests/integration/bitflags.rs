@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use bitflags2::bitflags;
+use schemars::bitflags2::Flags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 1 << 1;
+        const EXECUTE = 1 << 2;
+        const ALL = Self::READ.bits() | Self::WRITE.bits() | Self::EXECUTE.bits();
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(transparent)]
+struct PermissionsWrapper(#[schemars(with = "Flags<Permissions>")] Permissions);
+
+#[test]
+fn bitflags() {
+    test!(PermissionsWrapper)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip(
+            [
+                Permissions::empty(),
+                Permissions::READ,
+                Permissions::READ | Permissions::EXECUTE,
+                Permissions::all(),
+                Permissions::from_bits_retain(0x88),
+            ]
+            .map(PermissionsWrapper),
+        )
+        .assert_allows_de_roundtrip([json!("  "), json!("WRITE|READ"), json!(" READ | 0x10 ")])
+        .assert_rejects_de([
+            json!(1),
+            json!("read"),
+            json!("READ |"),
+            json!("READ | | WRITE"),
+            json!("READ WRITE"),
+            json!("DELETE"),
+            json!("0x"),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
@@ -0,0 +1,30 @@
+use bstr1::{BStr, BString};
+
+use crate::prelude::*;
+
+#[test]
+fn bstring() {
+    test!(BString)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            BString::default(),
+            BString::from("test"),
+            BString::from(vec![0xFF, 0x00, 0xC3, 0x28]),
+        ])
+        .assert_allows_de_roundtrip([json!("test"), json!([255, 0, 195, 40])])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            is_array_of_u64,
+            "FIXME schema allows out-of-range positive integers",
+        ));
+}
+
+#[test]
+fn bstr() {
+    test!(&BStr).assert_identical::<BString>();
+}
+
+fn is_array_of_u64(value: &Value) -> bool {
+    value
+        .as_array()
+        .is_some_and(|a| a.iter().all(Value::is_u64))
+}
@@ -0,0 +1,16 @@
+use camino1::{Utf8Path, Utf8PathBuf};
+
+use crate::prelude::*;
+
+#[test]
+fn utf8_path_buf() {
+    test!(Utf8PathBuf)
+        .assert_identical::<String>()
+        .assert_allows_ser_roundtrip(["".into(), "/foo/bar.txt".into(), "baz".into()])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn utf8_path() {
+    test!(&Utf8Path).assert_identical::<&str>();
+}
@@ -0,0 +1,121 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use std::collections::BTreeMap;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Config {
+    #[schemars(unique_items)]
+    hosts: Vec<String>,
+    #[schemars(properties(min = 1))]
+    labels: BTreeMap<String, String>,
+    #[schemars(properties(max = 2))]
+    annotations: BTreeMap<String, String>,
+    #[schemars(contains(pattern = "admin", min = 1, max = 2))]
+    roles: Vec<String>,
+    #[schemars(length(max = 3), inner(unique_items, inner(properties(equal = 1))))]
+    matrix: Vec<Vec<BTreeMap<String, bool>>>,
+}
+
+fn valid_value() -> Value {
+    json!({
+        "hosts": ["a", "b"],
+        "labels": { "app": "schemars" },
+        "annotations": {},
+        "roles": ["admin", "user"],
+        "matrix": [[{ "a": true }, { "b": true }]],
+    })
+}
+
+#[test]
+fn collection_validation() {
+    test!(Config)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([valid_value()])
+        .custom(|schema, _| {
+            let roles = &schema.as_value()["properties"]["roles"];
+            assert_eq!(roles["minContains"], json!(1));
+            assert_eq!(roles["maxContains"], json!(2));
+
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            for (key, value) in [
+                ("hosts", json!(["a", "a"])),
+                ("labels", json!({})),
+                ("annotations", json!({ "a": "1", "b": "2", "c": "3" })),
+                ("roles", json!(["user"])),
+                // `maxContains` isn't supported by the version of `jsonschema` used in tests, so
+                // `["admin", "sysadmin", "superadmin"]` isn't checked here
+                ("matrix", json!([[{ "a": true }, { "a": true }]])),
+                ("matrix", json!([[{}]])),
+            ] {
+                let mut invalid = valid_value();
+                invalid[key] = value;
+                assert!(
+                    !compiled.is_valid(&invalid),
+                    "schema should reject {invalid}"
+                );
+            }
+        });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct NonStringItems {
+    #[schemars(contains(pattern = "1"))]
+    ids: Vec<i32>,
+    #[schemars(contains(pattern = "1", min = 1))]
+    counts: Vec<u8>,
+}
+
+#[test]
+fn contains_non_string_items() {
+    test!(NonStringItems)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({ "ids": [2, 3], "counts": [2] })])
+        .custom(|schema, _| {
+            let properties = &schema.as_value()["properties"];
+            for property in ["ids", "counts"] {
+                let property = &properties[property];
+                assert_eq!(property.get("contains"), None);
+                assert_eq!(property.get("minContains"), None);
+                assert_eq!(property.get("maxContains"), None);
+            }
+        });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Roles {
+    #[schemars(contains(pattern = "admin", min = 1, max = 2))]
+    admins: Vec<String>,
+    #[schemars(contains(pattern = "guest", min = 0, max = 1))]
+    guests: Vec<String>,
+}
+
+#[test]
+fn contains_draft07() {
+    test!(Roles, SchemaSettings::draft07())
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({ "admins": ["admin"], "guests": [] })])
+        .custom(|schema, _| {
+            let properties = &schema.as_value()["properties"];
+            assert_eq!(properties["admins"]["contains"]["pattern"], json!("admin"));
+            // With `minContains: 0`, `contains` would make the schema stricter on its own
+            assert_eq!(properties["guests"].get("contains"), None);
+            for property in ["admins", "guests"] {
+                assert_eq!(properties[property].get("minContains"), None);
+                assert_eq!(properties[property].get("maxContains"), None);
+            }
+        });
+}
+
+#[test]
+fn contains_openapi3() {
+    test!(Roles, SchemaSettings::openapi3())
+        .assert_snapshot()
+        .custom(|schema, _| {
+            let properties = &schema.as_value()["properties"];
+            for property in ["admins", "guests"] {
+                assert_eq!(properties[property].get("contains"), None);
+                assert_eq!(properties[property].get("minContains"), None);
+                assert_eq!(properties[property].get("maxContains"), None);
+            }
+        });
+}
@@ -0,0 +1,11 @@
+use compact_str09::CompactString;
+
+use crate::prelude::*;
+
+#[test]
+fn compact_str() {
+    test!(CompactString)
+        .assert_identical::<String>()
+        .assert_allows_ser_roundtrip(["".into(), "test".into()])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
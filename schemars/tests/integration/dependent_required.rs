@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[schemars(dependent_required(tls_cert = ["tls_key"], "ca-file" = ["tls_cert", "tls_key"]))]
+#[schemars(one_of_required("password", "token"))]
+struct Connection {
+    host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tls_cert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tls_key: Option<String>,
+    #[serde(rename = "ca-file", skip_serializing_if = "Option::is_none")]
+    ca_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+}
+
+fn valid_values() -> impl IntoIterator<Item = Value> {
+    [
+        json!({ "host": "localhost", "password": "hunter2" }),
+        json!({ "host": "localhost", "token": "abc" }),
+        json!({ "host": "localhost", "token": "abc", "tls_key": "key" }),
+        json!({ "host": "localhost", "token": "abc", "tls_cert": "cert", "tls_key": "key" }),
+        json!({
+            "host": "localhost",
+            "token": "abc",
+            "tls_cert": "cert",
+            "tls_key": "key",
+            "ca-file": "ca"
+        }),
+    ]
+}
+
+fn invalid_values() -> impl IntoIterator<Item = Value> {
+    [
+        json!({ "host": "localhost" }),
+        json!({ "host": "localhost", "password": "hunter2", "token": "abc" }),
+        json!({ "host": "localhost", "token": "abc", "tls_cert": "cert" }),
+        json!({ "host": "localhost", "token": "abc", "tls_key": "key", "ca-file": "ca" }),
+    ]
+}
+
+fn assert_validates(schema: &schemars::Schema) {
+    let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+    for valid in valid_values() {
+        assert!(compiled.is_valid(&valid), "schema should allow {valid}");
+    }
+    for invalid in invalid_values() {
+        assert!(
+            !compiled.is_valid(&invalid),
+            "schema should reject {invalid}"
+        );
+    }
+}
+
+#[test]
+fn dependent_required() {
+    test!(Connection)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip(valid_values())
+        .custom(|schema, _| {
+            assert_eq!(
+                schema.get("dependentRequired"),
+                Some(&json!({
+                    "tls_cert": ["tls_key"],
+                    "ca-file": ["tls_cert", "tls_key"]
+                }))
+            );
+        });
+}
+
+#[test]
+fn dependent_required_draft07() {
+    test!(Connection, SchemaSettings::draft07())
+        .assert_snapshot()
+        .assert_allows_de_roundtrip(valid_values())
+        .custom(|schema, _| {
+            assert!(schema.get("dependentRequired").is_none());
+            assert_eq!(
+                schema.get("dependencies"),
+                Some(&json!({
+                    "tls_cert": ["tls_key"],
+                    "ca-file": ["tls_cert", "tls_key"]
+                }))
+            );
+            assert_validates(schema);
+        });
+}
+
+#[test]
+fn dependent_required_openapi3() {
+    test!(Connection, SchemaSettings::openapi3())
+        .assert_snapshot()
+        .custom(|schema, _| {
+            assert!(schema.get("dependentRequired").is_none());
+            assert_validates(schema);
+        });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[schemars(one_of_required("email", "phone"), one_of_required("name", "nickname"))]
+struct Contact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+}
+
+#[test]
+fn one_of_required_multiple() {
+    test!(Contact)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([
+            json!({ "email": "a@example.com", "name": "A" }),
+            json!({ "phone": "123", "nickname": "B" }),
+        ])
+        .custom(|schema, _| {
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            for invalid in [
+                json!({ "email": "a@example.com" }),
+                json!({ "email": "a@example.com", "phone": "123", "name": "A" }),
+                json!({ "phone": "123", "name": "A", "nickname": "B" }),
+            ] {
+                assert!(
+                    !compiled.is_valid(&invalid),
+                    "schema should reject {invalid}"
+                );
+            }
+        });
+}
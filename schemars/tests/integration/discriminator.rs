@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Square {
+    side: f64,
+}
+
+/// A shape.
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(discriminator)]
+enum Shape {
+    /// A circle.
+    Circle {
+        radius: f64,
+    },
+    Square(Square),
+    Empty,
+}
+
+impl Shape {
+    fn values() -> impl IntoIterator<Item = Self> {
+        [
+            Self::Circle { radius: 1.5 },
+            Self::Square(Square { side: 2.0 }),
+            Self::Empty,
+        ]
+    }
+}
+
+#[test]
+fn discriminator() {
+    test!(Shape)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip(Shape::values())
+        .assert_rejects_de([
+            json!({ "type": "circle" }),
+            json!({ "type": "triangle" }),
+            json!({ "radius": 1.5 }),
+        ]);
+}
+
+#[test]
+fn discriminator_openapi3() {
+    test!(Shape, SchemaSettings::openapi3())
+        .assert_snapshot()
+        .custom(|schema, _| {
+            assert_eq!(
+                schema.get("discriminator"),
+                Some(&json!({
+                    "propertyName": "type",
+                    "mapping": {
+                        "circle": "#/components/schemas/ShapeCircle",
+                        "square": "#/components/schemas/ShapeSquare",
+                        "empty": "#/components/schemas/ShapeEmpty"
+                    }
+                }))
+            );
+            assert_openapi3_compatible(schema.as_value());
+        });
+}
+
+// Checks for keywords that aren't supported by OpenAPI 3.0, including in the variant definitions
+// under `components/schemas`.
+fn assert_openapi3_compatible(value: &Value) {
+    match value {
+        Value::Object(obj) => {
+            assert!(!obj.contains_key("const"), "unexpected `const` in {value}");
+            assert!(
+                !obj.contains_key("$ref") || obj.len() == 1,
+                "unexpected `$ref` siblings in {value}"
+            );
+            obj.values().for_each(assert_openapi3_compatible);
+        }
+        Value::Array(array) => array.iter().for_each(assert_openapi3_compatible),
+        _ => {}
+    }
+}
+
+#[test]
+fn discriminator_inline_subschemas() {
+    let mut settings = SchemaSettings::default();
+    settings.inline_subschemas = true;
+
+    test!(Shape, settings)
+        .assert_allows_ser_roundtrip(Shape::values())
+        .custom(|schema, _| {
+            assert_eq!(
+                schema.get("discriminator"),
+                Some(&json!({ "propertyName": "type" }))
+            );
+            assert!(schema.get("$defs").is_none());
+        });
+}
@@ -0,0 +1,11 @@
+use ecow02::EcoString;
+
+use crate::prelude::*;
+
+#[test]
+fn ecow() {
+    test!(EcoString)
+        .assert_identical::<String>()
+        .assert_allows_ser_roundtrip(["".into(), "test".into()])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
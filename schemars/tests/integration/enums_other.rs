@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Internal {
+    Circle {
+        radius: f64,
+    },
+    #[serde(alias = "rectangle")]
+    Square {
+        side: f64,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[test]
+fn internally_tagged_other() {
+    test!(Internal)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            Internal::Circle { radius: 1.0 },
+            Internal::Square { side: 2.0 },
+            Internal::Unknown,
+        ])
+        .assert_allows_de_roundtrip([
+            json!({ "kind": "unknown" }),
+            json!({ "kind": "triangle" }),
+            json!({ "kind": "triangle", "sides": 3 }),
+        ])
+        .assert_rejects_de([
+            json!({}),
+            json!({ "kind": 1 }),
+            json!({ "kind": "circle" }),
+            json!({ "kind": "rectangle" }),
+        ]);
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "t", content = "c", deny_unknown_fields)]
+enum Adjacent {
+    Number(i32),
+    Flag,
+    #[serde(other, rename(serialize = "Other"))]
+    Unknown,
+}
+
+#[test]
+fn adjacently_tagged_other() {
+    test!(Adjacent)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([Adjacent::Number(1), Adjacent::Flag])
+        .assert_allows_ser_only([Adjacent::Unknown])
+        .assert_allows_de_roundtrip([
+            json!({ "t": "Unknown" }),
+            json!({ "t": "Other" }),
+            json!({ "t": "Something" }),
+            json!({ "t": "Number", "c": 1 }),
+        ])
+        .assert_rejects_de([
+            json!({ "t": "Number" }),
+            json!({ "t": "Something", "x": 1 }),
+        ]);
+}
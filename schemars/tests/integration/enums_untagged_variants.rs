@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Struct {
+    foo: i32,
+    bar: bool,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum External {
+    Unit,
+    Struct {
+        baz: String,
+    },
+    #[serde(untagged)]
+    UntaggedString(String),
+    #[serde(untagged)]
+    UntaggedStruct(Struct),
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum ExternalUnitOnly {
+    A,
+    B,
+    #[serde(untagged)]
+    Other(bool),
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "tag")]
+enum Internal {
+    Unit,
+    Struct {
+        baz: String,
+    },
+    #[serde(untagged)]
+    Untagged(Struct),
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent {
+    Unit,
+    Newtype(i32),
+    #[serde(untagged)]
+    UntaggedBool(bool),
+    #[serde(untagged)]
+    UntaggedUnit,
+}
+
+#[test]
+fn externally_tagged_with_untagged_variants() {
+    test!(External)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            External::Unit,
+            External::Struct {
+                baz: "hello".to_owned(),
+            },
+            External::UntaggedString("world".to_owned()),
+            External::UntaggedStruct(Struct { foo: 1, bar: true }),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn externally_tagged_unit_only_with_untagged_variant() {
+    test!(ExternalUnitOnly)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            ExternalUnitOnly::A,
+            ExternalUnitOnly::B,
+            ExternalUnitOnly::Other(true),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn internally_tagged_with_untagged_variant() {
+    test!(Internal)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            Internal::Unit,
+            Internal::Struct {
+                baz: "hello".to_owned(),
+            },
+            Internal::Untagged(Struct { foo: 1, bar: true }),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_array,
+            "Internally tagged enums can be deserialized from sequences, which the schema doesn't allow",
+        ));
+}
+
+#[test]
+fn adjacently_tagged_with_untagged_variants() {
+    test!(Adjacent)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            Adjacent::Unit,
+            Adjacent::Newtype(1),
+            Adjacent::UntaggedBool(false),
+            Adjacent::UntaggedUnit,
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
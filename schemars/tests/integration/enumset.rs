@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use enumset1::{EnumSet, EnumSetType};
+
+#[derive(EnumSetType, JsonSchema, Debug, Deserialize, Serialize)]
+#[enumset(crate_name = "enumset1")]
+enum Permission {
+    Read,
+    Write,
+    Execute,
+}
+
+#[derive(EnumSetType, JsonSchema, Debug, Deserialize, Serialize)]
+#[enumset(crate_name = "enumset1", serialize_repr = "list")]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn enumset_integer() {
+    test!(EnumSet<Permission>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            EnumSet::empty(),
+            Permission::Read | Permission::Execute,
+            EnumSet::all(),
+        ])
+        .assert_rejects_de([json!(-1), json!(1.5)])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            |v| v.is_u64() || v.is_array(),
+            "schema can't tell whether the set is serialized as an integer or an array",
+        ));
+}
+
+#[test]
+fn enumset_list() {
+    test!(EnumSet<Color>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([EnumSet::empty(), Color::Red | Color::Blue, EnumSet::all()])
+        .assert_allows_de_roundtrip([json!(["Green", "Red"])])
+        .assert_rejects_de([json!(["Purple"]), json!("Red")]);
+}
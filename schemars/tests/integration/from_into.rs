@@ -0,0 +1,131 @@
+use crate::prelude::*;
+
+#[derive(JsonSchema, Deserialize, Serialize, Clone)]
+#[serde(try_from = "String", into = "String")]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl TryFrom<String> for Version {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (major, minor) = value.split_once('.').ok_or("missing `.`")?;
+        Ok(Version {
+            major: major.parse().map_err(|_| "invalid major version")?,
+            minor: minor.parse().map_err(|_| "invalid minor version")?,
+        })
+    }
+}
+
+impl From<Version> for String {
+    fn from(value: Version) -> Self {
+        format!("{}.{}", value.major, value.minor)
+    }
+}
+
+#[test]
+fn try_from_and_into() {
+    test!(Version)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([Version { major: 1, minor: 2 }])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_string,
+            "Version's schema allows any string, but deserialization requires a valid version",
+        ));
+}
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct Repr {
+    id: u64,
+    name: Option<String>,
+}
+
+/// A user.
+#[derive(JsonSchema, Deserialize, Serialize, Clone)]
+#[serde(from = "Repr", into = "Repr")]
+struct User {
+    id: u64,
+    name: String,
+}
+
+impl From<Repr> for User {
+    fn from(value: Repr) -> Self {
+        User {
+            id: value.id,
+            name: value.name.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<User> for Repr {
+    fn from(value: User) -> Self {
+        Repr {
+            id: value.id,
+            name: Some(value.name).filter(|n| !n.is_empty()),
+        }
+    }
+}
+
+#[test]
+fn from_and_into() {
+    test!(User)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            User {
+                id: 1,
+                name: "Alice".to_owned(),
+            },
+            User {
+                id: 2,
+                name: String::new(),
+            },
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(from = "u8")]
+struct FromOnly {
+    value: u8,
+}
+
+impl From<u8> for FromOnly {
+    fn from(value: u8) -> Self {
+        FromOnly { value }
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Clone)]
+#[serde(into = "bool")]
+enum IntoOnly {
+    Yes,
+    No,
+}
+
+impl From<IntoOnly> for bool {
+    fn from(value: IntoOnly) -> Self {
+        matches!(value, IntoOnly::Yes)
+    }
+}
+
+#[test]
+fn from_only() {
+    test!(FromOnly)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([FromOnly { value: 0 }, FromOnly { value: 255 }])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            |v| v.as_u64().is_some_and(|u| u > u8::MAX.into()),
+            "u8's schema doesn't include a maximum",
+        ));
+}
+
+#[test]
+fn into_only() {
+    test!(IntoOnly)
+        .assert_snapshot()
+        .assert_allows_ser_only([IntoOnly::Yes, IntoOnly::No])
+        .assert_allows_de_roundtrip([json!("Yes"), json!("No")])
+        .assert_rejects_de([json!(true), json!(false)]);
+}
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/// Gets the weather forecast for a location.
+#[schemars::function]
+fn get_weather(
+    #[schemars(length(min = 1), description = "The city to look up.")] location: String,
+    #[serde(default = "default_days")]
+    #[schemars(range(max = 10))]
+    days: u8,
+    unit: Option<Unit>,
+) -> String {
+    let unit = match unit {
+        Some(Unit::Fahrenheit) => "°F",
+        _ => "°C",
+    };
+    format!("{location}: 20{unit} for {days} days")
+}
+
+fn default_days() -> u8 {
+    1
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum Unit {
+    Celsius,
+    Fahrenheit,
+}
+
+#[test]
+fn function_args() {
+    test!(GetWeatherArgs).assert_snapshot();
+}
+
+#[test]
+fn function_call() {
+    assert_eq!(
+        GetWeatherArgs::call_json(json!({ "location": "London" })).unwrap(),
+        "London: 20°C for 1 days"
+    );
+    assert_eq!(
+        GetWeatherArgs::call_json(json!({
+            "location": "New York",
+            "days": 3,
+            "unit": "Fahrenheit"
+        }))
+        .unwrap(),
+        "New York: 20°F for 3 days"
+    );
+    assert!(GetWeatherArgs::call_json(json!({ "days": 3 })).is_err());
+
+    let args = GetWeatherArgs {
+        location: "Paris".to_owned(),
+        days: 2,
+        unit: None,
+    };
+    assert_eq!(args.call(), "Paris: 20°C for 2 days");
+}
+
+#[schemars::function]
+#[serde(deny_unknown_fields, rename = "reset")]
+pub fn reset_all() {}
+
+#[test]
+fn function_no_args() {
+    test!(ResetAllArgs).assert_snapshot();
+
+    ResetAllArgs::call_json(json!({})).unwrap();
+    assert!(ResetAllArgs::call_json(json!({ "force": true })).is_err());
+}
@@ -157,3 +157,100 @@ fn garde_attrs_newtype() {
         .assert_rejects_invalid([GardeAttrNewType(11)])
         .assert_matches_de_roundtrip(arbitrary_values());
 }
+
+#[derive(JsonSchema, Deserialize, Serialize, Validate)]
+pub struct GardeStringAttrStruct {
+    #[garde(ascii)]
+    ascii_str: String,
+    #[garde(alphanumeric)]
+    alphanumeric_str: String,
+    #[garde(prefix("https://"), suffix(".rs"))]
+    prefix_suffix_str: String,
+    #[garde(pattern(r"^[a-z.:/]+$"), prefix("https://"))]
+    pattern_and_prefix_str: String,
+    #[garde(credit_card)]
+    credit_card: String,
+    #[garde(phone_number)]
+    phone_number: String,
+    #[garde(byte_length(max = 16))]
+    byte_length_str: String,
+    #[garde(matches(byte_length_str))]
+    matches_str: String,
+    #[garde(inner(ascii, prefix("tag:")))]
+    tags: Vec<String>,
+}
+
+impl Default for GardeStringAttrStruct {
+    fn default() -> Self {
+        Self {
+            ascii_str: "Hello, world!".to_owned(),
+            alphanumeric_str: "Café123".to_owned(),
+            prefix_suffix_str: "https://docs.rs".to_owned(),
+            pattern_and_prefix_str: "https://graham.cool".to_owned(),
+            credit_card: "4111111111111111".to_owned(),
+            phone_number: "+16502530000".to_owned(),
+            byte_length_str: "bytes".to_owned(),
+            matches_str: "bytes".to_owned(),
+            tags: vec!["tag:rust".to_owned()],
+        }
+    }
+}
+
+impl GardeStringAttrStruct {
+    pub fn invalid_values() -> impl IntoIterator<Item = Self> {
+        static MUTATORS: &[fn(&mut GardeStringAttrStruct)] = &[
+            |v| v.ascii_str = "Héllo".to_owned(),
+            |v| v.alphanumeric_str = "abc 123".to_owned(),
+            |v| v.prefix_suffix_str = "http://docs.rs".to_owned(),
+            |v| v.prefix_suffix_str = "https://docs.rs/".to_owned(),
+            |v| v.pattern_and_prefix_str = "https://Graham.cool".to_owned(),
+            |v| v.pattern_and_prefix_str = "ftp://graham.cool".to_owned(),
+            |v| v.credit_card = "4111 1111 1111 1111".to_owned(),
+            |v| v.tags = vec!["rust".to_owned()],
+            |v| v.tags = vec!["tag:rüst".to_owned()],
+        ];
+        MUTATORS.iter().map(|f| {
+            let mut result = GardeStringAttrStruct::default();
+            f(&mut result);
+            result
+        })
+    }
+}
+
+#[test]
+fn garde_string_attrs() {
+    test!(GardeStringAttrStruct)
+        .with_validator(|v| v.validate().is_ok())
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip_default()
+        .assert_rejects_invalid(GardeStringAttrStruct::invalid_values())
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+// `byte_length` and `matches` have no JSON Schema equivalent, so are ignored
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[schemars(rename = "GardeStringAttrStruct")]
+pub struct SchemarsStringAttrStruct {
+    #[schemars(ascii)]
+    ascii_str: String,
+    #[schemars(alphanumeric)]
+    alphanumeric_str: String,
+    #[schemars(prefix("https://"), suffix(".rs"))]
+    prefix_suffix_str: String,
+    #[schemars(pattern(r"^[a-z.:/]+$"), prefix("https://"))]
+    pattern_and_prefix_str: String,
+    #[schemars(credit_card)]
+    credit_card: String,
+    #[schemars(phone_number)]
+    phone_number: String,
+    byte_length_str: String,
+    matches_str: String,
+    #[schemars(inner(ascii, prefix("tag:")))]
+    tags: Vec<String>,
+}
+
+#[test]
+fn schemars_string_attrs() {
+    test!(SchemarsStringAttrStruct).assert_identical::<GardeStringAttrStruct>();
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use hashbrown016::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[test]
+fn hashbrown_map() {
+    test!(HashMap<String, bool>)
+        .assert_identical::<BTreeMap<String, bool>>()
+        .assert_allows_ser_roundtrip([
+            HashMap::new(),
+            HashMap::from_iter([("key".to_owned(), true)]),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn hashbrown_set() {
+    test!(HashSet<String>)
+        .assert_identical::<BTreeSet<String>>()
+        .assert_allows_ser_roundtrip([HashSet::new(), HashSet::from_iter(["test".to_owned()])])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use heapless08::{String as HeaplessString, Vec as HeaplessVec};
+
+#[test]
+fn heapless_vec() {
+    test!(HeaplessVec<i32, 8>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([
+            HeaplessVec::new(),
+            HeaplessVec::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap(),
+        ])
+        .assert_matches_de_roundtrip(
+            (0..16).map(|len| Value::Array((0..len).map(Value::from).collect())),
+        )
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            is_array_of_u64,
+            "FIXME schema allows out-of-range positive integers",
+        ));
+}
+
+#[test]
+fn heapless_string() {
+    test!(HeaplessString<8>)
+        .assert_identical::<String>()
+        .assert_allows_ser_roundtrip(["".try_into().unwrap(), "12345678".try_into().unwrap()])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_string,
+            "There's not a good way to express UTF-8 byte length in JSON schema, so schema ignores the String's capacity.",
+        ));
+}
+
+fn is_array_of_u64(value: &Value) -> bool {
+    value
+        .as_array()
+        .is_some_and(|a| a.iter().all(Value::is_u64))
+}
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use http1::header::{ACCEPT, CONTENT_TYPE};
+use http1::{HeaderMap, HeaderValue, Method, StatusCode, Uri, Version};
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct RequestLog {
+    #[serde(with = "http_serde::method")]
+    #[schemars(with = "Method")]
+    method: Method,
+    #[serde(with = "http_serde::uri")]
+    #[schemars(with = "Uri")]
+    uri: Uri,
+    #[serde(with = "http_serde::version")]
+    #[schemars(with = "Version")]
+    version: Version,
+    #[serde(with = "http_serde::status_code")]
+    #[schemars(with = "StatusCode")]
+    status: StatusCode,
+    #[serde(with = "http_serde::header_map")]
+    #[schemars(with = "HeaderMap")]
+    headers: HeaderMap,
+}
+
+// `http` types don't implement `Serialize`/`Deserialize`, so test them via newtype wrappers
+#[derive(JsonSchema, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct WithMethod(
+    #[serde(with = "http_serde::method")]
+    #[schemars(with = "Method")]
+    Method,
+);
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct WithUri(
+    #[serde(with = "http_serde::uri")]
+    #[schemars(with = "Uri")]
+    Uri,
+);
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct WithVersion(
+    #[serde(with = "http_serde::version")]
+    #[schemars(with = "Version")]
+    Version,
+);
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct WithStatusCode(
+    #[serde(with = "http_serde::status_code")]
+    #[schemars(with = "StatusCode")]
+    StatusCode,
+);
+
+#[derive(JsonSchema, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct WithHeaderMap(
+    #[serde(with = "http_serde::header_map")]
+    #[schemars(with = "HeaderMap")]
+    HeaderMap,
+);
+
+fn headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+    headers.append(ACCEPT, HeaderValue::from_static("application/xml"));
+    headers
+}
+
+#[test]
+fn http() {
+    test!(RequestLog).assert_snapshot();
+
+    test!(WithMethod)
+        .assert_allows_ser_roundtrip([
+            WithMethod(Method::GET),
+            WithMethod(Method::PATCH),
+            WithMethod(Method::from_bytes(b"PURGE").unwrap()),
+        ])
+        .assert_rejects_de([json!(""), json!("GET /")])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(WithUri)
+        .assert_allows_ser_roundtrip([
+            WithUri(Uri::from_static("https://example.com/path?query=1")),
+            WithUri(Uri::from_static("/index.html")),
+            WithUri(Uri::from_static("*")),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_string,
+            "`uri-reference` format allows some strings that `Uri` rejects, e.g. \"\"",
+        ));
+
+    test!(WithVersion)
+        .assert_allows_ser_roundtrip([WithVersion(Version::HTTP_11), WithVersion(Version::HTTP_2)])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(WithStatusCode)
+        .assert_allows_ser_roundtrip([
+            WithStatusCode(StatusCode::OK),
+            WithStatusCode(StatusCode::from_u16(999).unwrap()),
+        ])
+        .assert_rejects_de([json!(99), json!(1000)])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(WithHeaderMap)
+        .assert_allows_ser_roundtrip([WithHeaderMap(HeaderMap::new()), WithHeaderMap(headers())])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use im15::{hashmap, hashset, ordmap, ordset, vector, HashMap, HashSet, OrdMap, OrdSet, Vector};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[test]
+fn im_vector() {
+    test!(Vector<usize>)
+        .assert_identical::<Vec<usize>>()
+        .assert_allows_ser_roundtrip([vector![], vector![1, 2, 3, 4, 5]])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn im_hashmap() {
+    test!(HashMap<String, bool>)
+        .assert_identical::<BTreeMap<String, bool>>()
+        .assert_allows_ser_roundtrip([hashmap! {}, hashmap! {"key".to_owned() => true}])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn im_hashset() {
+    test!(HashSet<String>)
+        .assert_identical::<BTreeSet<String>>()
+        .assert_allows_ser_roundtrip([hashset![], hashset!["test".to_owned()]])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn im_ordmap() {
+    test!(OrdMap<String, bool>)
+        .assert_identical::<BTreeMap<String, bool>>()
+        .assert_allows_ser_roundtrip([ordmap! {}, ordmap! {"key".to_owned() => true}])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[test]
+fn im_ordset() {
+    test!(OrdSet<String>)
+        .assert_identical::<BTreeSet<String>>()
+        .assert_allows_ser_roundtrip([ordset![], ordset!["test".to_owned()]])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
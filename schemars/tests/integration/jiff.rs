@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use jiff02::civil::{Date, DateTime, Time};
+use jiff02::tz::{Offset, TimeZone};
+use jiff02::{SignedDuration, Span, Timestamp, ToSpan, Zoned};
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct JiffTypes {
+    timestamp: Timestamp,
+    zoned: Zoned,
+    date: Date,
+    time: Time,
+    date_time: DateTime,
+    span: Span,
+    signed_duration: SignedDuration,
+}
+
+fn date_time() -> DateTime {
+    jiff02::civil::datetime(2024, 6, 19, 15, 22, 45, 123_000_000)
+}
+
+#[test]
+fn jiff() {
+    test!(JiffTypes).assert_snapshot();
+
+    test!(Timestamp)
+        .assert_allows_ser_roundtrip([
+            Timestamp::UNIX_EPOCH,
+            date_time().to_zoned(TimeZone::UTC).unwrap().timestamp(),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    // Use a fixed offset time zone, which can be deserialized without a time zone database
+    test!(Zoned)
+        .assert_allows_ser_roundtrip([date_time()
+            .to_zoned(TimeZone::fixed(Offset::constant(-4)))
+            .unwrap()])
+        .assert_rejects_de([json!("2024-06-19T15:22:45-04:00")])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(Date)
+        .assert_allows_ser_roundtrip([date_time().date()])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(Time)
+        .assert_allows_ser_roundtrip([Time::midnight(), date_time().time(), Time::MAX])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_string,
+            "Custom format 'partial-time', so arbitrary strings technically allowed by schema",
+        ));
+
+    test!(DateTime)
+        .assert_allows_ser_roundtrip([date_time()])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_string,
+            "Custom format 'partial-date-time', so arbitrary strings technically allowed by schema",
+        ));
+
+    test!(Span)
+        .assert_allows_ser_roundtrip([
+            Span::new(),
+            1.year().months(2).days(3).hours(4),
+            (-90).minutes(),
+            1.second().milliseconds(500),
+        ])
+        .assert_allows_de_roundtrip([json!("P1W"), json!("-PT1.5S")]);
+
+    test!(SignedDuration).assert_allows_ser_roundtrip([
+        SignedDuration::ZERO,
+        SignedDuration::from_secs(5400),
+        SignedDuration::from_millis(-1500),
+    ]);
+}
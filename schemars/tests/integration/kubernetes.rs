@@ -0,0 +1,348 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use schemars::json_schema;
+use schemars::transform::{KubernetesStructural, ReplaceNullType, Transform};
+use schemars::Schema;
+use serde_json::Map;
+use std::collections::BTreeMap;
+
+/// Spec for a `Document` custom resource.
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DocumentSpec {
+    title: String,
+    hidden: Option<bool>,
+    #[schemars(range(min = 1))]
+    replicas: i32,
+    #[serde(default)]
+    labels: BTreeMap<String, String>,
+    annotations: Option<BTreeMap<String, Value>>,
+    extra: Value,
+    port: IntOrString,
+    source: Source,
+    auth: Option<Auth>,
+    phase: Phase,
+    conditions: Vec<Condition>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(untagged)]
+enum IntOrString {
+    Int(i32),
+    String(String),
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum Source {
+    Git {
+        url: String,
+        revision: Option<String>,
+    },
+    ConfigMap {
+        name: String,
+    },
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all_fields = "camelCase")]
+enum Auth {
+    Basic { username: String },
+    Token { secret_name: String },
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum Phase {
+    /// Waiting to be scheduled.
+    Pending,
+    Running,
+    Failed,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Condition {
+    r#type: String,
+    status: bool,
+    last_transition_time: Option<String>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, String>,
+}
+
+#[test]
+fn kubernetes_crd_spec() {
+    test!(DocumentSpec, SchemaSettings::kubernetes())
+        .assert_snapshot()
+        .custom(|schema, _| assert_structural(schema.as_value(), "", false));
+}
+
+#[test]
+fn kubernetes_value() {
+    test!(Value, SchemaSettings::kubernetes()).custom(|schema, _| {
+        assert_eq!(
+            schema.as_value(),
+            &json!({
+                "title": "AnyValue",
+                "x-kubernetes-preserve-unknown-fields": true
+            })
+        );
+    });
+}
+
+#[test]
+fn kubernetes_int_or_string() {
+    test!(IntOrString, SchemaSettings::kubernetes()).custom(|schema, _| {
+        assert_eq!(
+            schema.as_value(),
+            &json!({
+                "title": "IntOrString",
+                "x-kubernetes-int-or-string": true
+            })
+        );
+    });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(tag = "kind")]
+#[schemars(discriminator)]
+enum Storage {
+    Disk {
+        size: String,
+    },
+    Bucket {
+        name: String,
+        region: Option<String>,
+    },
+}
+
+#[test]
+fn kubernetes_discriminator() {
+    test!(Storage, SchemaSettings::kubernetes()).custom(|schema, _| {
+        assert!(schema.get("discriminator").is_none());
+        assert_structural(schema.as_value(), "", false);
+    });
+}
+
+/// Label selector, as used in many Kubernetes resources.
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LabelSelector {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    match_expressions: Option<Vec<LabelSelectorRequirement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    match_labels: Option<BTreeMap<String, String>>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct LabelSelectorRequirement {
+    key: String,
+    operator: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<String>>,
+}
+
+/// The `openAPIV3Schema` of the `ServiceMonitor` CRD from prometheus-operator v0.89.0, as accepted
+/// by the Kubernetes API server.
+fn recorded_crd_schema() -> Value {
+    serde_json::from_str(include_str!(
+        "fixtures/servicemonitors.monitoring.coreos.com.json"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn kubernetes_recorded_crd() {
+    let recorded = recorded_crd_schema();
+    assert_structural(&recorded, "", false);
+
+    let mut schema = Schema::try_from(recorded).unwrap();
+    KubernetesStructural.transform(&mut schema);
+    assert_structural(schema.as_value(), "", false);
+}
+
+#[test]
+fn kubernetes_matches_recorded_crd() {
+    let mut expected = recorded_crd_schema()["properties"]["spec"]["properties"]["selector"].take();
+    remove_annotations(&mut expected);
+
+    test!(LabelSelector, SchemaSettings::kubernetes()).custom(|schema, _| {
+        let mut actual = schema.as_value().clone();
+        remove_annotations(&mut actual);
+        assert_eq!(actual, expected);
+    });
+}
+
+// Removes keywords that don't affect validation or whether a schema is structural, and list/map
+// type extensions that can't be derived from Rust types.
+fn remove_annotations(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            for key in [
+                "title",
+                "description",
+                "nullable",
+                "x-kubernetes-list-type",
+                "x-kubernetes-map-type",
+            ] {
+                obj.remove(key);
+            }
+            obj.values_mut().for_each(remove_annotations);
+        }
+        Value::Array(array) => array.iter_mut().for_each(remove_annotations),
+        _ => {}
+    }
+}
+
+#[test]
+fn replace_null_type() {
+    let mut schema = json_schema!({
+        "type": "object",
+        "properties": {
+            "a": { "type": ["string", "null"] },
+            "b": { "type": ["string", "integer", "null"] },
+            "c": {
+                "anyOf": [
+                    { "type": "object", "properties": { "x": { "type": "integer" } } },
+                    { "type": "null" }
+                ]
+            },
+            "d": {
+                "description": "Conflicting keyword",
+                "oneOf": [
+                    { "description": "Inner", "type": "string" },
+                    { "type": "null" }
+                ]
+            },
+            "e": { "type": ["null"] },
+            "f": {
+                "anyOf": [
+                    { "type": "null" },
+                    { "type": "null" }
+                ]
+            }
+        }
+    });
+    ReplaceNullType.transform(&mut schema);
+
+    assert_eq!(
+        schema,
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "a": { "type": "string", "nullable": true },
+                "b": { "type": ["string", "integer"], "nullable": true },
+                "c": {
+                    "nullable": true,
+                    "type": "object",
+                    "properties": { "x": { "type": "integer" } }
+                },
+                "d": {
+                    "description": "Conflicting keyword",
+                    "oneOf": [
+                        { "description": "Inner", "type": "string" }
+                    ],
+                    "nullable": true
+                },
+                "e": { "type": ["null"] },
+                "f": {
+                    "anyOf": [
+                        { "type": "null" },
+                        { "type": "null" }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+/// Checks the rules for a Kubernetes structural schema, as validated by the API server for CRDs:
+/// https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definitions/#specifying-a-structural-schema
+fn assert_structural(schema: &Value, pointer: &str, in_junctor: bool) {
+    let obj = schema
+        .as_object()
+        .unwrap_or_else(|| panic!("{pointer}: must be an object"));
+
+    for forbidden in [
+        "$ref",
+        "$schema",
+        "definitions",
+        "dependencies",
+        "patternProperties",
+        "additionalItems",
+        "const",
+        "discriminator",
+    ] {
+        assert!(!obj.contains_key(forbidden), "{pointer}: has `{forbidden}`");
+    }
+    assert!(
+        obj.get("additionalProperties") != Some(&Value::Bool(false)),
+        "{pointer}: has `additionalProperties: false`"
+    );
+    assert!(
+        !(obj.contains_key("properties") && obj.contains_key("additionalProperties")),
+        "{pointer}: has both `properties` and `additionalProperties`"
+    );
+
+    if in_junctor {
+        for forbidden in [
+            "type",
+            "description",
+            "default",
+            "additionalProperties",
+            "nullable",
+        ] {
+            assert!(
+                !obj.contains_key(forbidden),
+                "{pointer}: has `{forbidden}` within a logical junctor"
+            );
+        }
+    } else {
+        assert!(
+            obj.get("type").is_some_and(Value::is_string)
+                || obj.contains_key("x-kubernetes-int-or-string")
+                || obj.contains_key("x-kubernetes-preserve-unknown-fields"),
+            "{pointer}: must have a single `type`"
+        );
+    }
+
+    for (key, value) in obj {
+        match key.as_str() {
+            "properties" => {
+                for (name, property) in value.as_object().unwrap() {
+                    assert_structural(property, &format!("{pointer}/{key}/{name}"), in_junctor);
+                }
+            }
+            "items" | "additionalProperties" => {
+                assert_structural(value, &format!("{pointer}/{key}"), in_junctor);
+            }
+            "anyOf" if is_int_or_string(obj, value) => {}
+            "allOf" | "anyOf" | "oneOf" => {
+                for (i, subschema) in value.as_array().unwrap().iter().enumerate() {
+                    let subpointer = format!("{pointer}/{key}/{i}");
+                    assert_structural(subschema, &subpointer, true);
+                    let properties = subschema.get("properties").and_then(Value::as_object);
+                    let required = subschema.get("required").and_then(Value::as_array);
+                    let names = properties
+                        .into_iter()
+                        .flat_map(|p| p.keys().map(String::as_str))
+                        .chain(required.into_iter().flatten().filter_map(Value::as_str));
+                    for name in names {
+                        assert!(
+                            obj.get("properties").and_then(|p| p.get(name)).is_some(),
+                            "{subpointer}: property `{name}` is not specified outside of `{key}`"
+                        );
+                    }
+                }
+            }
+            "not" => assert_structural(value, &format!("{pointer}/{key}"), true),
+            _ => {}
+        }
+    }
+}
+
+// `x-kubernetes-int-or-string` allows an `anyOf` of the integer and string types, which would
+// otherwise be forbidden within a logical junctor
+fn is_int_or_string(obj: &Map<String, Value>, any_of: &Value) -> bool {
+    obj.get("x-kubernetes-int-or-string") == Some(&Value::Bool(true))
+        && any_of == &json!([{ "type": "integer" }, { "type": "string" }])
+}
@@ -1,34 +1,76 @@
 #![allow(clippy::disallowed_names)]
 
+mod alias;
+#[cfg(feature = "arcstr1")]
+mod arcstr;
 #[cfg(feature = "arrayvec07")]
 mod arrayvec;
+#[cfg(any(feature = "num_bigint04", feature = "num_rational04"))]
+mod bignum;
+#[cfg(feature = "bitflags2")]
+mod bitflags;
 mod bound;
+#[cfg(feature = "bstr1")]
+mod bstr;
 #[cfg(feature = "bytes1")]
 mod bytes;
+#[cfg(feature = "camino1")]
+mod camino;
 #[cfg(feature = "chrono04")]
 mod chrono;
+mod collection_validation;
+#[cfg(feature = "compact_str09")]
+mod compact_str;
 mod contract;
 mod crate_alias;
 #[cfg(any(feature = "rust_decimal1", feature = "bigdecimal04"))]
 mod decimal;
 mod default;
+mod dependent_required;
 mod deprecated;
+mod discriminator;
 mod docs;
+#[cfg(feature = "ecow02")]
+mod ecow;
 #[cfg(feature = "either1")]
 mod either;
 mod enum_repr;
 mod enums;
 mod enums_deny_unknown_fields;
 mod enums_flattened;
+mod enums_other;
+mod enums_untagged_variants;
+#[cfg(feature = "enumset1")]
+mod enumset;
 mod examples;
 mod extend;
 mod flatten;
+mod from_into;
 mod from_value;
+mod function;
 mod garde;
+#[cfg(feature = "hashbrown016")]
+mod hashbrown;
+#[cfg(feature = "heapless08")]
+mod heapless;
+#[cfg(feature = "http1")]
+mod http;
+#[cfg(feature = "im15")]
+mod im;
 #[cfg(feature = "indexmap2")]
 mod indexmap;
 mod inline_subschemas;
+#[cfg(feature = "jiff02")]
+mod jiff;
+mod kubernetes;
 mod macros;
+mod map_keys;
+mod map_validation;
+mod numeric_validation;
+mod openapi_parameters;
+#[cfg(feature = "ordered_float5")]
+mod ordered_float;
+mod patch;
 mod remote_derive;
 mod same_name;
 mod schema_name;
@@ -42,9 +84,17 @@ mod smallvec;
 #[cfg(feature = "smol_str02")]
 mod smol_str;
 mod std_types;
+mod strict_mode;
+mod string_content;
 mod structs;
+#[cfg(feature = "thin_vec02")]
+mod thin_vec;
+#[cfg(feature = "time03")]
+mod time;
 mod transform;
 mod transparent;
+#[cfg(feature = "ulid1")]
+mod ulid;
 #[cfg(feature = "url2")]
 mod url;
 #[cfg(feature = "uuid1")]
@@ -0,0 +1,163 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use schemars::map::KeyedMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::net::Ipv4Addr;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(transparent)]
+struct KeyedBTreeMap<K: Ord, V>(#[schemars(with = "KeyedMap<K, V>")] BTreeMap<K, V>);
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+#[serde(transparent)]
+struct KeyedHashMap<K: Eq + Hash, V>(#[schemars(with = "KeyedMap<K, V>")] HashMap<K, V>);
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+enum Documented {
+    /// The first variant.
+    First,
+    /// The second variant.
+    Second,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct Id(u64);
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct UserId(String);
+
+#[derive(Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct NotJsonSchema(String);
+
+#[test]
+fn string_keys() {
+    test!(KeyedBTreeMap<String, i32>).custom(|schema, _| {
+        assert_eq!(
+            schema.as_value(),
+            &json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Map_of_int32",
+                "type": "object",
+                "additionalProperties": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn unsigned_integer_keys() {
+    test!(KeyedBTreeMap<u32, bool>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip(
+            [
+                BTreeMap::new(),
+                BTreeMap::from_iter([(0, true), (123, false)]),
+            ]
+            .map(KeyedBTreeMap),
+        )
+        .assert_rejects_de([
+            json!({ "-1": true }),
+            json!({ "1.5": true }),
+            json!({ "01": true }),
+            json!({ "key": true }),
+        ]);
+}
+
+#[test]
+fn signed_integer_keys() {
+    test!(KeyedHashMap<i64, bool>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([KeyedHashMap(HashMap::from_iter([
+            (0, true),
+            (-123, false),
+            (456, true),
+        ]))])
+        .assert_rejects_de([json!({ "-": true }), json!({ "key": true })]);
+}
+
+#[test]
+fn newtype_integer_keys() {
+    test!(KeyedBTreeMap<Id, bool>)
+        .assert_allows_ser_roundtrip([KeyedBTreeMap(BTreeMap::from_iter([(Id(1), true)]))])
+        .custom(|schema, _| {
+            assert_eq!(
+                schema.get("propertyNames"),
+                Some(&json!({
+                    "type": "string",
+                    "pattern": "^(0|[1-9][0-9]*)$"
+                }))
+            );
+        });
+}
+
+#[test]
+fn enum_keys() {
+    test!(KeyedHashMap<Color, bool>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([KeyedHashMap(HashMap::from_iter([
+            (Color::Red, true),
+            (Color::Blue, false),
+        ]))])
+        .assert_matches_de_roundtrip(arbitrary_values())
+        .assert_rejects_de([json!({ "Red": true }), json!({ "purple": true })]);
+}
+
+#[test]
+fn documented_enum_keys() {
+    test!(KeyedBTreeMap<Documented, bool>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([KeyedBTreeMap(BTreeMap::from_iter([(
+            Documented::Second,
+            true,
+        )]))])
+        .assert_matches_de_roundtrip(arbitrary_values())
+        .assert_rejects_de([json!({ "Third": true })]);
+}
+
+#[test]
+fn formatted_string_keys() {
+    test!(KeyedBTreeMap<Ipv4Addr, bool>)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([KeyedBTreeMap(BTreeMap::from_iter([(
+            Ipv4Addr::LOCALHOST,
+            true,
+        )]))])
+        .assert_rejects_de([json!({ "localhost": true })]);
+}
+
+#[test]
+fn integer_keys_openapi3() {
+    test!(KeyedBTreeMap<u32, bool>, SchemaSettings::openapi3()).custom(|schema, _| {
+        assert!(schema.get("propertyNames").is_none());
+    });
+}
+
+#[test]
+fn unconstrained_newtype_keys() {
+    test!(KeyedBTreeMap<UserId, u8>).custom(|schema, _| {
+        assert!(schema.get("propertyNames").is_none());
+        assert!(schema.get("$defs").is_none());
+    });
+}
+
+#[test]
+fn plain_map_keys() {
+    test!(BTreeMap<NotJsonSchema, bool>)
+        .assert_identical::<BTreeMap<String, bool>>()
+        .custom(|schema, _| {
+            assert!(schema.get("propertyNames").is_none());
+        });
+    test!(BTreeMap<Color, bool>).assert_identical::<BTreeMap<String, bool>>();
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+use schemars::map::KeyedMap;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Quotas {
+    #[schemars(keys(pattern = r"^[a-z_]+$", length(max = 10)), values(range(min = 0)))]
+    btree: BTreeMap<String, i64>,
+    #[schemars(keys(pattern = r"^[a-z_]+$", length(max = 10)), values(range(min = 0)))]
+    hash: HashMap<String, i64>,
+    #[schemars(
+        with = "KeyedMap<u16, String>",
+        keys(length(max = 4)),
+        values(length(min = 1))
+    )]
+    ports: BTreeMap<u16, String>,
+    #[schemars(values(range(max = 10)))]
+    optional: Option<BTreeMap<String, u8>>,
+}
+
+fn valid_value() -> Value {
+    json!({
+        "btree": { "cpu": 2, "memory_gb": 0 },
+        "hash": { "disk": 100 },
+        "ports": { "8080": "http" },
+        "optional": { "retries": 10 },
+    })
+}
+
+#[test]
+fn map_validation() {
+    test!(Quotas)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([valid_value()])
+        .custom(|schema, _| {
+            let properties = &schema.as_value()["properties"];
+            assert_eq!(properties["btree"], properties["hash"]);
+
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            for (key, value) in [
+                ("btree", json!({ "CPU": 1 })),
+                ("btree", json!({ "far_too_long": 1 })),
+                ("btree", json!({ "cpu": -1 })),
+                ("hash", json!({ "Disk": 1 })),
+                ("hash", json!({ "disk": -1 })),
+                ("ports", json!({ "80808": "http" })),
+                ("ports", json!({ "8080": "" })),
+                ("optional", json!({ "retries": 11 })),
+            ] {
+                let mut invalid = valid_value();
+                invalid[key] = value;
+                assert!(
+                    !compiled.is_valid(&invalid),
+                    "schema should reject {invalid}"
+                );
+            }
+        });
+}
+
+#[cfg(feature = "indexmap2")]
+#[test]
+fn map_validation_indexmap() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct IndexMapQuotas {
+        #[schemars(keys(pattern = r"^[a-z_]+$", length(max = 10)), values(range(min = 0)))]
+        index: indexmap2::IndexMap<String, i64>,
+        #[schemars(keys(pattern = r"^[a-z_]+$", length(max = 10)), values(range(min = 0)))]
+        btree: BTreeMap<String, i64>,
+    }
+
+    test!(IndexMapQuotas).custom(|schema, _| {
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(properties["index"], properties["btree"]);
+    });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+enum Resource {
+    Cpu,
+    MemoryGb,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct EnumKeyQuotas {
+    #[schemars(
+        with = "KeyedMap<Resource, i64>",
+        keys(length(max = 3)),
+        values(range(min = 0))
+    )]
+    limits: BTreeMap<Resource, i64>,
+}
+
+#[test]
+fn map_validation_enum_keys() {
+    test!(EnumKeyQuotas)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({ "limits": { "cpu": 2 } })])
+        .assert_rejects_de([json!({ "limits": { "gpu": 1 } })])
+        .custom(|schema, _| {
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            let too_long = json!({ "limits": { "memory_gb": 1 } });
+            assert!(
+                !compiled.is_valid(&too_long),
+                "schema should reject {too_long}"
+            );
+        });
+}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Measurements {
+    #[schemars(range(exclusive_min = 0.0))]
+    length_metres: f64,
+    #[schemars(multiple_of = 0.5, range(min = 0, exclusive_max = 10))]
+    shoe_size: f32,
+    #[schemars(multiple_of = 15, range(exclusive_min = -60, max = 60))]
+    utc_offset_minutes: i16,
+    #[schemars(inner(multiple_of = 2))]
+    even_numbers: Vec<u32>,
+}
+
+#[test]
+fn numeric_validation() {
+    test!(Measurements)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({
+            "length_metres": 0.1,
+            "shoe_size": 9.5,
+            "utc_offset_minutes": 60,
+            "even_numbers": [0, 2, 4],
+        })])
+        .custom(|schema, _| assert_rejects_invalid(schema));
+}
+
+#[test]
+fn numeric_validation_openapi3() {
+    test!(Measurements, SchemaSettings::openapi3())
+        .assert_snapshot()
+        .custom(|schema, _| {
+            // The boolean form of `exclusiveMinimum` is not valid in newer versions of JSON Schema,
+            // so check the schema's structure instead of validating values against it
+            let length = &schema.as_value()["properties"]["length_metres"];
+            assert_eq!(length["minimum"], json!(0.0));
+            assert_eq!(length["exclusiveMinimum"], json!(true));
+
+            let offset = &schema.as_value()["properties"]["utc_offset_minutes"];
+            assert_eq!(offset["minimum"], json!(-60));
+            assert_eq!(offset["exclusiveMinimum"], json!(true));
+            assert_eq!(offset["maximum"], json!(60));
+            assert!(offset.get("exclusiveMaximum").is_none());
+        });
+}
+
+fn assert_rejects_invalid(schema: &schemars::Schema) {
+    let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+    for (key, value) in [
+        ("length_metres", json!(0.0)),
+        ("shoe_size", json!(9.25)),
+        ("shoe_size", json!(10.0)),
+        ("utc_offset_minutes", json!(-60)),
+        ("utc_offset_minutes", json!(10)),
+        ("even_numbers", json!([1])),
+    ] {
+        let mut invalid = json!({
+            "length_metres": 0.1,
+            "shoe_size": 9.5,
+            "utc_offset_minutes": 60,
+            "even_numbers": [0, 2, 4],
+        });
+        invalid[key] = value;
+        assert!(
+            !compiled.is_valid(&invalid),
+            "schema should reject {invalid}"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+#![allow(deprecated)]
+
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use schemars::openapi::{parameters_for, ParameterError, ParameterLocation};
+use std::collections::BTreeMap;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Pagination {
+    page: u32,
+    per_page: Option<u32>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct SearchQuery {
+    /// The search term.
+    q: String,
+    #[deprecated]
+    #[serde(default)]
+    exact: bool,
+    sort: Option<SortOrder>,
+    tags: Vec<String>,
+    #[serde(flatten)]
+    pagination: Pagination,
+}
+
+#[test]
+fn query_parameters() {
+    let mut generator = SchemaSettings::default().into_generator();
+    let parameters = parameters_for::<SearchQuery>(&mut generator, ParameterLocation::Query);
+
+    assert_eq!(
+        parameters,
+        Ok(vec![
+            json!({
+                "name": "q",
+                "in": "query",
+                "required": true,
+                "description": "The search term.",
+                "schema": {
+                    "type": "string"
+                }
+            }),
+            json!({
+                "name": "exact",
+                "in": "query",
+                "required": false,
+                "deprecated": true,
+                "schema": {
+                    "type": "boolean",
+                    "default": false
+                }
+            }),
+            json!({
+                "name": "sort",
+                "in": "query",
+                "required": false,
+                "schema": {
+                    "anyOf": [
+                        { "$ref": "#/$defs/SortOrder" },
+                        { "type": "null" }
+                    ]
+                }
+            }),
+            json!({
+                "name": "tags",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            }),
+            json!({
+                "name": "page",
+                "in": "query",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "uint32",
+                    "minimum": 0
+                }
+            }),
+            json!({
+                "name": "per_page",
+                "in": "query",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "uint32",
+                    "minimum": 0
+                }
+            }),
+        ])
+    );
+
+    assert!(generator.definitions().contains_key("SortOrder"));
+}
+
+#[test]
+fn query_parameters_openapi3() {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let parameters =
+        parameters_for::<SearchQuery>(&mut generator, ParameterLocation::Query).unwrap();
+
+    assert_eq!(
+        parameters[2],
+        json!({
+            "name": "sort",
+            "in": "query",
+            "required": false,
+            "schema": {
+                "allOf": [
+                    { "$ref": "#/components/schemas/SortOrder" }
+                ],
+                "nullable": true
+            }
+        })
+    );
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct PathParams {
+    id: u64,
+    version: Option<String>,
+}
+
+#[test]
+fn optional_path_parameter() {
+    let mut generator = SchemaSettings::default().into_generator();
+
+    assert_eq!(
+        parameters_for::<PathParams>(&mut generator, ParameterLocation::Path),
+        Err(ParameterError::OptionalPathParameter("version".to_owned()))
+    );
+    assert!(parameters_for::<PathParams>(&mut generator, ParameterLocation::Header).is_ok());
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct NestedQuery {
+    q: String,
+    pagination: Pagination,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct MapQuery {
+    filters: BTreeMap<String, String>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct NestedArrayQuery {
+    matrix: Vec<Vec<i32>>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct ValueQuery {
+    anything: Value,
+}
+
+#[test]
+fn non_scalar_properties() {
+    let mut generator = SchemaSettings::default().into_generator();
+
+    assert_eq!(
+        parameters_for::<NestedQuery>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NonScalarProperty("pagination".to_owned()))
+    );
+    assert_eq!(
+        parameters_for::<MapQuery>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NonScalarProperty("filters".to_owned()))
+    );
+    assert_eq!(
+        parameters_for::<NestedArrayQuery>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NonScalarProperty("matrix".to_owned()))
+    );
+    assert_eq!(
+        parameters_for::<ValueQuery>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NonScalarProperty("anything".to_owned()))
+    );
+}
+
+#[test]
+fn non_object_types() {
+    let mut generator = SchemaSettings::default().into_generator();
+
+    assert_eq!(
+        parameters_for::<SortOrder>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NotAnObject)
+    );
+    assert_eq!(
+        parameters_for::<Vec<String>>(&mut generator, ParameterLocation::Query),
+        Err(ParameterError::NotAnObject)
+    );
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use ordered_float5::{NotNan, OrderedFloat};
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct Measurements {
+    ordered: OrderedFloat<f64>,
+    not_nan: NotNan<f32>,
+}
+
+#[test]
+fn ordered_float() {
+    test!(Measurements).assert_snapshot();
+
+    test!(OrderedFloat<f64>)
+        .assert_identical::<f64>()
+        .assert_allows_ser_roundtrip([OrderedFloat(0.0), OrderedFloat(-1.5), OrderedFloat(1e300)])
+        .assert_matches_de_roundtrip(arbitrary_values());
+
+    test!(NotNan<f64>)
+        .assert_allows_ser_roundtrip([NotNan::new(0.0).unwrap(), NotNan::new(1.5).unwrap()])
+        .assert_matches_de_roundtrip(arbitrary_values())
+        .custom(|schema, _| {
+            assert_eq!(
+                schema.get("description"),
+                Some(&json!("A floating-point number which is not NaN"))
+            );
+        });
+}
@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use schemars::patch::MergePatch;
+use std::collections::BTreeMap;
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct User {
+    name: String,
+    email: Option<String>,
+    #[serde(default)]
+    admin: bool,
+    tags: Vec<String>,
+    address: Address,
+    previous_address: Option<Address>,
+    settings: BTreeMap<String, i32>,
+    role: Role,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+enum Role {
+    Reader,
+    Writer { scopes: Vec<String> },
+}
+
+fn patch(value: Value) -> MergePatch<User> {
+    MergePatch::new(value)
+}
+
+#[test]
+fn merge_patch() {
+    test!(MergePatch<User>)
+        .assert_snapshot()
+        .assert_allows_ser_only([
+            patch(json!({})),
+            patch(json!({ "name": "Alice", "email": null })),
+            patch(json!({ "address": { "city": "Paris" } })),
+            patch(json!({ "previous_address": null })),
+            patch(json!({ "previous_address": { "street": null } })),
+            patch(json!({ "settings": { "theme": 2, "old": null } })),
+            patch(json!({ "tags": ["a", "b"], "role": { "Writer": { "scopes": [] } } })),
+        ])
+        .custom(|schema, _| {
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            for invalid in [
+                json!(null),
+                json!({ "name": 1 }),
+                json!({ "tags": [null] }),
+                json!({ "address": { "city": 1 } }),
+                json!({ "role": { "Writer": {} } }),
+            ] {
+                assert!(
+                    !compiled.is_valid(&invalid),
+                    "patch schema should reject {invalid}"
+                );
+            }
+        });
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Node {
+    value: i32,
+    child: Option<Box<Node>>,
+}
+
+#[test]
+fn merge_patch_recursive() {
+    test!(MergePatch<Node>).assert_snapshot();
+}
+
+#[test]
+fn merge_patch_openapi3() {
+    test!(MergePatch<User>, SchemaSettings::openapi3()).assert_snapshot();
+}
+
+#[test]
+fn merge_patch_non_object() {
+    test!(MergePatch<Vec<String>>).custom(|schema, _| {
+        assert_eq!(
+            schema.as_value(),
+            &json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Array_of_stringPatch",
+                "type": "array",
+                "items": {
+                    "type": "string"
+                }
+            })
+        );
+    });
+    test!(MergePatch<Role>).custom(|schema, _| {
+        assert_eq!(schema.get("title").unwrap(), "RolePatch");
+        assert!(schema.get("oneOf").is_some());
+    });
+}
+
+// A user-defined type whose name clashes with the generated patch schema for `Address`
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct AddressPatch {
+    note: String,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Move {
+    from: Address,
+    to: Address,
+    change: AddressPatch,
+}
+
+#[test]
+fn merge_patch_name_clash() {
+    test!(MergePatch<Move>)
+        .assert_snapshot()
+        .custom(|schema, _| {
+            let defs = &schema.as_value()["$defs"];
+            assert_eq!(defs["AddressPatch"]["required"], json!(["note"]));
+            assert_eq!(
+                defs["AddressPatch2"]["properties"]["city"]["type"],
+                json!(["string", "null"])
+            );
+
+            let properties = &schema.as_value()["properties"];
+            assert_eq!(
+                properties["from"]["anyOf"][0]["$ref"],
+                json!("#/$defs/AddressPatch2")
+            );
+            assert_eq!(properties["to"], properties["from"]);
+        });
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+use schemars::generate::SchemaSettings;
+use schemars::transform::{StrictMode, StrictModeError};
+use std::collections::BTreeMap;
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+struct WeatherQuery {
+    /// The city to look up.
+    #[schemars(length(min = 1))]
+    location: String,
+    #[schemars(range(min = 1, max = 10))]
+    days: u8,
+    unit: Option<Unit>,
+    coordinates: Option<Coordinates>,
+    #[serde(default)]
+    include_hourly: bool,
+    tags: Vec<String>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+enum Unit {
+    #[default]
+    Celsius,
+    /// Only used in the US.
+    Fahrenheit,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+struct Coordinates {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+struct ToolCall {
+    query: Coordinates,
+    id: String,
+}
+
+#[test]
+fn strict_struct() {
+    // `location` has a `minLength`, which is not supported in strict mode
+    test!(WeatherQuery, SchemaSettings::llm_strict()).assert_snapshot();
+}
+
+#[test]
+fn strict_nested_struct() {
+    test!(ToolCall, SchemaSettings::llm_strict())
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip_default()
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+struct WithMap {
+    values: BTreeMap<String, i32>,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize, Default)]
+struct WithTuple {
+    pair: (i32, String),
+}
+
+#[test]
+fn try_transform_errors() {
+    let mut generator = SchemaSettings::draft2020_12().into_generator();
+
+    let mut schema = generator.root_schema_for::<ToolCall>();
+    assert_eq!(StrictMode.try_transform(&mut schema), Ok(()));
+
+    let mut schema = generator.root_schema_for::<WeatherQuery>();
+    assert_eq!(
+        StrictMode.try_transform(&mut schema),
+        Err(StrictModeError::UnsupportedKeyword {
+            pointer: "/properties/location".to_owned(),
+            keyword: "minLength".to_owned(),
+        })
+    );
+
+    let mut schema = generator.root_schema_for::<WithMap>();
+    assert_eq!(
+        StrictMode.try_transform(&mut schema),
+        Err(StrictModeError::UnsupportedKeyword {
+            pointer: "/properties/values".to_owned(),
+            keyword: "additionalProperties".to_owned(),
+        })
+    );
+
+    let mut schema = generator.root_schema_for::<WithTuple>();
+    assert_eq!(
+        StrictMode.try_transform(&mut schema),
+        Err(StrictModeError::UnsupportedKeyword {
+            pointer: "/properties/pair".to_owned(),
+            keyword: "prefixItems".to_owned(),
+        })
+    );
+
+    let mut schema = generator.root_schema_for::<Unit>();
+    assert_eq!(
+        StrictMode.try_transform(&mut schema),
+        Err(StrictModeError::RootNotObject)
+    );
+
+    let mut schema = generator.root_schema_for::<Value>();
+    assert_eq!(
+        StrictMode.try_transform(&mut schema),
+        Err(StrictModeError::RootNotObject)
+    );
+}
+
+#[test]
+fn openapi3_nullable() {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let mut schema = generator.root_schema_for::<WeatherQuery>();
+    let _ = StrictMode.try_transform(&mut schema);
+
+    assert_eq!(
+        schema.get("properties").unwrap()["coordinates"],
+        json!({
+            "anyOf": [
+                { "$ref": "#/components/schemas/Coordinates" },
+                { "type": "null" }
+            ]
+        })
+    );
+    assert_eq!(
+        schema.get("properties").unwrap()["include_hourly"],
+        json!({ "type": "boolean" })
+    );
+}
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+const PEM_MEDIA_TYPE: &str = "application/x-pem-file";
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Settings {
+    name: String,
+    port: u16,
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+struct Upload {
+    #[schemars(format = "hostname")]
+    host: String,
+    #[schemars(format = "duration")]
+    timeout: Option<String>,
+    #[schemars(inner(format = "uri-reference"))]
+    links: Vec<String>,
+    #[schemars(content_encoding = "base64", content_media_type = "image/png")]
+    thumbnail: String,
+    #[schemars(content_media_type = PEM_MEDIA_TYPE)]
+    certificate: String,
+    #[schemars(content_media_type = "application/json", content_schema = Settings)]
+    settings: String,
+    #[schemars(content_schema = "Vec<Settings>")]
+    more_settings: Option<String>,
+}
+
+#[test]
+fn string_content() {
+    test!(Upload)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({
+            "host": "example.com",
+            "timeout": "PT30S",
+            "links": ["../index.html"],
+            "thumbnail": "iVBORw0KGgo=",
+            "certificate": "-----BEGIN CERTIFICATE-----",
+            "settings": "{\"name\":\"test\",\"port\":8080}",
+            "more_settings": null,
+        })])
+        .custom(|schema, _| {
+            let properties = &schema.as_value()["properties"];
+            assert_eq!(properties["host"]["format"], "hostname");
+            assert_eq!(properties["links"]["items"]["format"], "uri-reference");
+            assert_eq!(
+                properties["certificate"]["contentMediaType"],
+                PEM_MEDIA_TYPE
+            );
+            assert_eq!(
+                properties["settings"]["contentSchema"],
+                json!({ "$ref": "#/$defs/Settings" })
+            );
+            assert_eq!(
+                properties["more_settings"]["contentSchema"]["items"],
+                json!({ "$ref": "#/$defs/Settings" })
+            );
+        });
+}
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use thin_vec02::{thin_vec, ThinVec};
+
+#[test]
+fn thin_vec() {
+    test!(ThinVec<usize>)
+        .assert_identical::<Vec<usize>>()
+        .assert_allows_ser_roundtrip([thin_vec![], thin_vec![1, 2, 3, 4, 5]])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
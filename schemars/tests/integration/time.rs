@@ -0,0 +1,90 @@
+use crate::prelude::*;
+use time03::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct TimeTypes {
+    offset_date_time: OffsetDateTime,
+    primitive_date_time: PrimitiveDateTime,
+    date: Date,
+    time: Time,
+    utc_offset: UtcOffset,
+    duration: Duration,
+}
+
+#[derive(JsonSchema, Serialize, Deserialize)]
+struct TimeSerdeHelpers {
+    #[serde(with = "time03::serde::rfc3339")]
+    #[schemars(with = "schemars::time03::Rfc3339")]
+    rfc3339: OffsetDateTime,
+    #[serde(with = "time03::serde::iso8601")]
+    #[schemars(with = "schemars::time03::Iso8601")]
+    iso8601: OffsetDateTime,
+    #[serde(with = "time03::serde::timestamp")]
+    #[schemars(with = "schemars::time03::UnixTimestamp")]
+    timestamp: OffsetDateTime,
+    #[serde(with = "time03::serde::timestamp::milliseconds::option")]
+    #[schemars(with = "Option<schemars::time03::UnixTimestamp>")]
+    timestamp_millis: Option<OffsetDateTime>,
+}
+
+fn date() -> Date {
+    Date::from_calendar_date(2024, Month::February, 29).unwrap()
+}
+
+fn time_of_day() -> Time {
+    Time::from_hms_nano(13, 5, 9, 123_000_000).unwrap()
+}
+
+fn offset() -> UtcOffset {
+    UtcOffset::from_hms(-5, -30, 0).unwrap()
+}
+
+#[test]
+fn time() {
+    test!(TimeTypes).assert_snapshot();
+
+    test!(OffsetDateTime)
+        .assert_allows_ser_roundtrip([
+            OffsetDateTime::UNIX_EPOCH,
+            PrimitiveDateTime::new(date(), time_of_day()).assume_offset(offset()),
+        ])
+        .assert_rejects_de([json!("2024-02-29T13:05:09Z")]);
+
+    test!(PrimitiveDateTime)
+        .assert_allows_ser_roundtrip([PrimitiveDateTime::new(date(), time_of_day())])
+        .assert_rejects_de([json!("2024-02-29T13:05:09")]);
+
+    test!(Date)
+        .assert_allows_ser_roundtrip([date()])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            Value::is_array,
+            "`time` also deserializes from its non-human-readable tuple representation",
+        ));
+
+    test!(Time)
+        .assert_allows_ser_roundtrip([Time::MIDNIGHT, time_of_day()])
+        .assert_matches_de_roundtrip(arbitrary_values_except(
+            |v| v.is_array() || v.is_string(),
+            "Custom format 'partial-time', so arbitrary strings technically allowed by schema",
+        ));
+
+    test!(UtcOffset).assert_allows_ser_roundtrip([UtcOffset::UTC, offset()]);
+
+    test!(Duration).assert_allows_ser_roundtrip([
+        Duration::ZERO,
+        Duration::new(90, 5),
+        Duration::seconds_f64(-1.5),
+    ]);
+}
+
+#[test]
+fn time_serde_helpers() {
+    test!(TimeSerdeHelpers)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([TimeSerdeHelpers {
+            rfc3339: OffsetDateTime::UNIX_EPOCH,
+            iso8601: PrimitiveDateTime::new(date(), time_of_day()).assume_offset(offset()),
+            timestamp: OffsetDateTime::UNIX_EPOCH,
+            timestamp_millis: Some(OffsetDateTime::UNIX_EPOCH),
+        }]);
+}
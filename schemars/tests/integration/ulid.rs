@@ -0,0 +1,18 @@
+use ulid1::Ulid;
+
+use crate::prelude::*;
+
+#[test]
+fn ulid() {
+    test!(Ulid)
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip([Ulid::nil(), Ulid::from(u128::MAX), Ulid::from(1234567890)])
+        .assert_allows_de_roundtrip([json!("01arz3ndektsv4rrffq69g5fav")])
+        .assert_rejects_de([
+            json!("01ARZ3NDEKTSV4RRFFQ69G5FA"),
+            json!("01ARZ3NDEKTSV4RRFFQ69G5FAVV"),
+            json!("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+            json!("01ARZ3NDEKTSV4RRFFQ69G5FAI"),
+        ])
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
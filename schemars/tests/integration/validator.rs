@@ -133,3 +133,124 @@ pub struct SchemarsAttrInner {
 fn schemars_attrs() {
     test!(SchemarsAttrStruct).assert_identical::<ValidateAttrStruct>();
 }
+
+#[derive(JsonSchema, Deserialize, Serialize, Validate)]
+pub struct ValidateMoreAttrStruct {
+    #[validate(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+    ratio: f64,
+    #[validate(range(min = 1, exclusive_max = 10))]
+    digit: u8,
+    #[validate(does_not_contain(pattern = "admin"))]
+    username: String,
+    #[validate(contains(pattern = "!"), does_not_contain(pattern = "password"))]
+    password: String,
+    #[validate(must_match(other = "password"))]
+    password_confirmation: String,
+    #[validate(nested)]
+    inner: Vec<ValidateAttrInner>,
+}
+
+impl Default for ValidateMoreAttrStruct {
+    fn default() -> Self {
+        Self {
+            ratio: 0.5,
+            digit: 1,
+            username: "user".to_owned(),
+            password: "hunter2!".to_owned(),
+            password_confirmation: "hunter2!".to_owned(),
+            inner: vec![ValidateAttrInner { x: 100 }],
+        }
+    }
+}
+
+impl ValidateMoreAttrStruct {
+    pub fn invalid_values() -> impl IntoIterator<Item = Self> {
+        static MUTATORS: &[fn(&mut ValidateMoreAttrStruct)] = &[
+            |v| v.ratio = 0.0,
+            |v| v.ratio = 1.0,
+            |v| v.digit = 0,
+            |v| v.digit = 10,
+            |v| v.username = "admin".to_owned(),
+            |v| v.username = "sysadmin1".to_owned(),
+            |v| v.password = "hunter2".to_owned(),
+            |v| v.password = "password!".to_owned(),
+            |v| v.inner = vec![ValidateAttrInner { x: 101 }],
+        ];
+        MUTATORS.iter().map(|f| {
+            let mut result = ValidateMoreAttrStruct::default();
+            f(&mut result);
+            // `must_match` has no JSON Schema equivalent, so keep it valid
+            result.password_confirmation = result.password.clone();
+            result
+        })
+    }
+}
+
+#[test]
+fn validate_more_attrs() {
+    test!(ValidateMoreAttrStruct)
+        .with_validator(|v| v.validate().is_ok())
+        .assert_snapshot()
+        .assert_allows_ser_roundtrip_default()
+        .assert_rejects_invalid(ValidateMoreAttrStruct::invalid_values())
+        .assert_matches_de_roundtrip(arbitrary_values());
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[schemars(rename = "ValidateMoreAttrStruct")]
+pub struct SchemarsMoreAttrStruct {
+    #[schemars(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+    ratio: f64,
+    #[schemars(range(min = 1, exclusive_max = 10))]
+    digit: u8,
+    #[schemars(does_not_contain(pattern = "admin"))]
+    username: String,
+    #[schemars(contains(pattern = "!"), does_not_contain("password"))]
+    password: String,
+    password_confirmation: String,
+    inner: Vec<ValidateAttrInner>,
+}
+
+#[test]
+fn schemars_more_attrs() {
+    test!(SchemarsMoreAttrStruct).assert_identical::<ValidateMoreAttrStruct>();
+}
+
+// The validator crate's `credit_card` and `non_control_character` rules require its optional `card`
+// and `unic` features, so these are only tested via the equivalent schemars attributes
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct SchemarsFormatAttrStruct {
+    #[schemars(credit_card)]
+    card_number: String,
+    #[schemars(non_control_character)]
+    display_name: String,
+}
+
+#[test]
+fn schemars_format_attrs() {
+    test!(SchemarsFormatAttrStruct)
+        .assert_snapshot()
+        .assert_allows_de_roundtrip([json!({
+            "card_number": "4111111111111111",
+            "display_name": "Graham\u{1F600}",
+        })])
+        .custom(|schema, _| {
+            let compiled = jsonschema::JSONSchema::compile(schema.as_value()).unwrap();
+            for invalid in [
+                json!({
+                    "card_number": "4111 1111 1111 1111",
+                    "display_name": "Graham",
+                }),
+                json!({
+                    "card_number": "4111111111111111",
+                    "display_name": "Graham\u{7}",
+                }),
+            ] {
+                assert!(
+                    !compiled.is_valid(&invalid),
+                    "schema should reject {invalid}"
+                );
+            }
+        });
+}
@@ -0,0 +1,25 @@
+use schemars::JsonSchema;
+
+#[derive(JsonSchema)]
+#[schemars(discriminator)]
+pub enum External {
+    A { a: i32 },
+}
+
+#[derive(JsonSchema)]
+#[schemars(tag = "type", discriminator)]
+pub struct Struct {
+    a: i32,
+}
+
+#[derive(JsonSchema)]
+#[schemars(tag = "type", discriminator)]
+pub enum WithUntagged {
+    A {
+        a: i32,
+    },
+    #[schemars(untagged)]
+    B(i32),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+pub struct Weather;
+
+impl Weather {
+    #[schemars::function]
+    pub fn method(&self, location: String) {}
+}
+
+#[schemars::function]
+pub fn generic<T>(value: T) {}
+
+#[schemars::function]
+pub fn destructured((a, b): (i32, i32)) {}
+
+#[schemars::function(rename = "x")]
+pub fn with_args() {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+pub struct Weather;
+
+impl Weather {
+    #[schemars::function]
+    pub fn new(name: String) -> Self {
+        Weather
+    }
+}
+
+#[schemars::function]
+pub fn borrowed(location: &str) {}
+
+#[schemars::function]
+pub fn lifetime(location: std::borrow::Cow<'_, str>) {}
+
+#[schemars::function]
+pub fn impl_trait(location: impl Into<String>) {}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+
+#[derive(JsonSchema)]
+#[schemars(dependent_required(a = ["unknown"]), one_of_required("b", "other"))]
+pub struct UnknownProperties {
+    a: Option<i32>,
+    b: Option<i32>,
+}
+
+#[derive(JsonSchema)]
+#[schemars(dependent_required(a = []), one_of_required("a"))]
+pub struct TooFewProperties {
+    a: Option<i32>,
+    b: Option<i32>,
+}
+
+#[derive(JsonSchema)]
+#[schemars(dependent_required(a = ["b"], a = ["c"]))]
+pub struct DuplicateKey {
+    a: Option<i32>,
+    b: Option<i32>,
+    c: Option<i32>,
+}
+
+#[derive(JsonSchema)]
+#[schemars(one_of_required("a", "b"))]
+pub struct Tuple(Option<i32>, Option<i32>);
+
+fn main() {}
@@ -39,4 +39,40 @@ pub struct Struct4(
     String,
 );
 
+#[derive(JsonSchema)]
+pub struct Struct5(
+    #[schemars(
+        range(min = 1, exclusive_min = 0, exclusive_max = 10, exclusive_max = 11),
+        length(exclusive_min = 1),
+        does_not_contain = "foo",
+        does_not_contain(foo = "bar"),
+        does_not_contain("baz"),
+        non_control_character(message = "message")
+    )]
+    String,
+);
+
+#[derive(JsonSchema)]
+pub struct Struct6(
+    #[schemars(
+        unique_items = true,
+        properties(min = 1, exclusive_max = 2),
+        contains("foo", min = 1),
+        does_not_contain(pattern = "foo", max = 1)
+    )]
+    Vec<String>,
+);
+
+#[derive(JsonSchema)]
+pub struct Struct7(
+    #[schemars(
+        email,
+        format = "hostname",
+        content_encoding,
+        content_schema = String,
+        content_schema = "Vec<String>"
+    )]
+    String,
+);
+
 fn main() {}
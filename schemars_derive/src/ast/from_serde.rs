@@ -1,5 +1,6 @@
 use super::*;
 use serde_derive_internals::ast as serde_ast;
+use serde_derive_internals::attr::TagType;
 use serde_derive_internals::Ctxt;
 
 pub trait FromSerde: Sized {
@@ -20,13 +21,70 @@ impl<'a> FromSerde for Container<'a> {
     type SerdeType = serde_ast::Container<'a>;
 
     fn from_serde(errors: &Ctxt, serde: Self::SerdeType) -> Result<Self, ()> {
-        Ok(Self {
+        let result = Self {
             ident: serde.ident,
             serde_attrs: serde.attrs,
             data: Data::from_serde(errors, serde.data)?,
             generics: serde.generics.clone(),
             attrs: ContainerAttrs::new(&serde.original.attrs, errors),
-        })
+        };
+
+        if result.attrs.discriminator {
+            let is_internally_tagged_enum = matches!(result.data, Data::Enum(_))
+                && matches!(result.serde_attrs.tag(), TagType::Internal { .. });
+            if !is_internally_tagged_enum {
+                errors.error_spanned_by(
+                    serde.original,
+                    "#[schemars(discriminator)] can only be used on internally tagged enums",
+                );
+            } else if let Data::Enum(variants) = &result.data {
+                for v in variants.iter().filter(|v| v.serde_attrs.untagged()) {
+                    errors.error_spanned_by(
+                        v.original,
+                        "#[schemars(discriminator)] cannot be used on enums with untagged variants",
+                    );
+                }
+            }
+        }
+
+        let property_name_attrs = result
+            .attrs
+            .dependent_required
+            .iter()
+            .flat_map(|(k, r)| core::iter::once(k).chain(r))
+            .chain(result.attrs.one_of_required.iter().flatten());
+        match &result.data {
+            Data::Struct(serde_ast::Style::Struct, fields) => {
+                // Properties may come from flattened fields, so we can't check them here
+                if !fields.iter().any(|f| f.serde_attrs.flatten()) {
+                    for name in property_name_attrs {
+                        let name_str = name.value();
+                        let is_known = fields.iter().any(|f| {
+                            f.serde_attrs.name().serialize_name() == name_str
+                                || f.serde_attrs.aliases().contains(&name_str)
+                        });
+                        if !is_known {
+                            errors.error_spanned_by(
+                                name,
+                                format_args!("unknown property name '{name_str}'"),
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {
+                if !result.attrs.dependent_required.is_empty()
+                    || !result.attrs.one_of_required.is_empty()
+                {
+                    errors.error_spanned_by(
+                        serde.original,
+                        "#[schemars(dependent_required(...))] and #[schemars(one_of_required(...))] can only be used on structs with named fields",
+                    );
+                }
+            }
+        }
+
+        Ok(result)
     }
 }
 
@@ -60,6 +60,18 @@ impl<'a> Container<'a> {
 
     pub fn add_mutators(&self, mutators: &mut Vec<TokenStream>) {
         self.attrs.common.add_mutators(mutators);
+
+        for (key, required) in &self.attrs.dependent_required {
+            mutators.push(quote! {
+                schemars::_private::insert_dependent_required(&mut #SCHEMA, #key, &[#(#required),*]);
+            });
+        }
+
+        for names in &self.attrs.one_of_required {
+            mutators.push(quote! {
+                schemars::_private::insert_one_of_required(&mut #SCHEMA, &[#(#names),*]);
+            });
+        }
     }
 }
 
@@ -94,6 +106,12 @@ impl<'a> Field<'a> {
         self.attrs.common.add_mutators(mutators);
         self.attrs.validation.add_mutators(mutators);
 
+        if let Some(content_schema) = &self.attrs.content_schema {
+            mutators.push(quote! {
+                schemars::_private::insert_content_schema::<#content_schema>(#GENERATOR, &mut #SCHEMA);
+            });
+        }
+
         if self.serde_attrs.skip_deserializing() {
             mutators.push(quote! {
                 schemars::_private::insert_metadata_property(&mut #SCHEMA, "readOnly", true);
@@ -3,12 +3,15 @@ mod parse_meta;
 mod schemars_to_serde;
 mod validation;
 
-use parse_meta::{parse_extensions, parse_name_value_expr, parse_name_value_lit_str};
+use parse_meta::{
+    parse_dependent_required, parse_extensions, parse_name_value_expr, parse_name_value_lit_str,
+    parse_name_value_type, parse_one_of_required, require_path_only,
+};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use serde_derive_internals::Ctxt;
 use syn::Ident;
-use syn::{punctuated::Punctuated, Attribute, Expr, ExprLit, Lit, Meta, Path, Type};
+use syn::{punctuated::Punctuated, Attribute, Expr, ExprLit, Lit, LitStr, Meta, Path, Type};
 use validation::ValidationAttrs;
 
 use crate::idents::SCHEMA;
@@ -31,6 +34,7 @@ pub struct FieldAttrs {
     pub common: CommonAttrs,
     pub with: Option<WithAttr>,
     pub validation: ValidationAttrs,
+    pub content_schema: Option<Type>,
 }
 
 #[derive(Debug, Default)]
@@ -39,6 +43,9 @@ pub struct ContainerAttrs {
     pub repr: Option<Type>,
     pub crate_name: Option<Path>,
     pub is_renamed: bool,
+    pub discriminator: bool,
+    pub dependent_required: Vec<(LitStr, Vec<LitStr>)>,
+    pub one_of_required: Vec<Vec<LitStr>>,
 }
 
 #[derive(Debug, Default)]
@@ -257,6 +264,11 @@ impl FieldAttrs {
                 }
             },
 
+            "content_schema" if cx.attr_type == "schemars" => match self.content_schema {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.content_schema = parse_name_value_type(meta, cx).ok(),
+            },
+
             _ => return Some(meta),
         }
 
@@ -299,6 +311,37 @@ impl ContainerAttrs {
             // The actual parsing of `rename` is done by serde
             "rename" => self.is_renamed = true,
 
+            "discriminator" if cx.attr_type == "schemars" => {
+                if self.discriminator {
+                    cx.duplicate_error(&meta);
+                } else if require_path_only(meta, cx).is_ok() {
+                    self.discriminator = true;
+                }
+            }
+
+            "dependent_required" if cx.attr_type == "schemars" => {
+                for dr in parse_dependent_required(meta, cx).into_iter().flatten() {
+                    if self
+                        .dependent_required
+                        .iter()
+                        .any(|(k, _)| k.value() == dr.key.value())
+                    {
+                        cx.error_spanned_by(
+                            &dr.key,
+                            format_args!("Duplicate dependent_required key '{}'", dr.key.value()),
+                        );
+                    } else {
+                        self.dependent_required.push((dr.key, dr.required));
+                    }
+                }
+            }
+
+            "one_of_required" if cx.attr_type == "schemars" => {
+                if let Ok(names) = parse_one_of_required(meta, cx) {
+                    self.one_of_required.push(names);
+                }
+            }
+
             _ => return Some(meta),
         };
 
@@ -1,8 +1,11 @@
 use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
 use syn::{
+    bracketed,
+    ext::IdentExt,
     parse::{Parse, ParseStream, Parser},
     punctuated::Punctuated,
-    Expr, ExprLit, Lit, LitStr, Meta, MetaList, MetaNameValue,
+    Expr, ExprLit, Ident, Lit, LitStr, Meta, MetaList, MetaNameValue, Type,
 };
 
 use super::{path_str, AttrCtxt};
@@ -81,6 +84,18 @@ pub fn parse_name_value_lit_str<T: Parse>(meta: Meta, cx: &AttrCtxt) -> Result<T
     parse_lit_str(lit_str, cx)
 }
 
+pub fn parse_name_value_type(meta: Meta, cx: &AttrCtxt) -> Result<Type, ()> {
+    match parse_name_value_expr(meta, cx)? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => parse_lit_str(lit_str, cx),
+        expr => syn::parse2(expr.to_token_stream()).map_err(|_| {
+            cx.error_spanned_by(&expr, "expected a type");
+        }),
+    }
+}
+
 fn parse_lit_str<T: Parse>(lit_str: LitStr, cx: &AttrCtxt) -> Result<T, ()> {
     lit_str.parse().map_err(|_| {
         cx.error_spanned_by(
@@ -106,20 +121,45 @@ pub fn parse_extensions(
     parse_meta_list_with(&meta, cx, parser)
 }
 
+pub fn parse_dependent_required(
+    meta: Meta,
+    cx: &AttrCtxt,
+) -> Result<impl IntoIterator<Item = DependentRequired>, ()> {
+    let parser = Punctuated::<DependentRequired, Token![,]>::parse_terminated;
+    parse_meta_list_with(&meta, cx, parser)
+}
+
+pub fn parse_one_of_required(meta: Meta, cx: &AttrCtxt) -> Result<Vec<LitStr>, ()> {
+    let parser = Punctuated::<LitStr, Token![,]>::parse_terminated;
+    let names = parse_meta_list_with(&meta, cx, parser)?;
+
+    if names.len() < 2 {
+        cx.error_spanned_by(
+            meta,
+            "`one_of_required` attribute item requires at least two property names",
+        );
+        return Err(());
+    }
+
+    Ok(names.into_iter().collect())
+}
+
 pub fn parse_length_or_range(outer_meta: Meta, cx: &AttrCtxt) -> Result<LengthOrRange, ()> {
     let outer_name = path_str(outer_meta.path());
     let mut result = LengthOrRange::default();
 
     for nested_meta in parse_nested_meta(outer_meta, cx)? {
         match path_str(nested_meta.path()).as_str() {
-            "min" => match (&result.min, &result.equal) {
-                (Some(_), _) => cx.duplicate_error(&nested_meta),
-                (_, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "equal"),
+            "min" => match (&result.min, &result.equal, &result.exclusive_min) {
+                (Some(_), _, _) => cx.duplicate_error(&nested_meta),
+                (_, Some(_), _) => cx.mutual_exclusive_error(&nested_meta, "equal"),
+                (_, _, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "exclusive_min"),
                 _ => result.min = parse_name_value_expr_handle_lit_str(nested_meta, cx).ok(),
             },
-            "max" => match (&result.max, &result.equal) {
-                (Some(_), _) => cx.duplicate_error(&nested_meta),
-                (_, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "equal"),
+            "max" => match (&result.max, &result.equal, &result.exclusive_max) {
+                (Some(_), _, _) => cx.duplicate_error(&nested_meta),
+                (_, Some(_), _) => cx.mutual_exclusive_error(&nested_meta, "equal"),
+                (_, _, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "exclusive_max"),
                 _ => result.max = parse_name_value_expr_handle_lit_str(nested_meta, cx).ok(),
             },
             "equal" => match (&result.min, &result.max, &result.equal) {
@@ -128,6 +168,26 @@ pub fn parse_length_or_range(outer_meta: Meta, cx: &AttrCtxt) -> Result<LengthOr
                 (_, _, Some(_)) => cx.duplicate_error(&nested_meta),
                 _ => result.equal = parse_name_value_expr_handle_lit_str(nested_meta, cx).ok(),
             },
+            "exclusive_min" if outer_name == "range" => {
+                match (&result.exclusive_min, &result.min) {
+                    (Some(_), _) => cx.duplicate_error(&nested_meta),
+                    (_, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "min"),
+                    _ => {
+                        result.exclusive_min =
+                            parse_name_value_expr_handle_lit_str(nested_meta, cx).ok();
+                    }
+                }
+            }
+            "exclusive_max" if outer_name == "range" => {
+                match (&result.exclusive_max, &result.max) {
+                    (Some(_), _) => cx.duplicate_error(&nested_meta),
+                    (_, Some(_)) => cx.mutual_exclusive_error(&nested_meta, "max"),
+                    _ => {
+                        result.exclusive_max =
+                            parse_name_value_expr_handle_lit_str(nested_meta, cx).ok();
+                    }
+                }
+            }
             unknown => {
                 if cx.attr_type == "schemars" {
                     cx.error_spanned_by(
@@ -145,6 +205,11 @@ pub fn parse_length_or_range(outer_meta: Meta, cx: &AttrCtxt) -> Result<LengthOr
 }
 
 pub fn parse_pattern(meta: Meta, cx: &AttrCtxt) -> Result<Expr, ()> {
+    // Schemars also allows e.g. `pattern = "..."` as well as the garde-style `pattern("...")`
+    if cx.attr_type == "schemars" && matches!(meta, Meta::NameValue(_)) {
+        return parse_name_value_expr(meta, cx);
+    }
+
     parse_meta_list_with(&meta, cx, Expr::parse)
 }
 
@@ -203,7 +268,9 @@ pub fn parse_validate_regex(outer_meta: Meta, cx: &AttrCtxt) -> Result<Expr, ()>
     })
 }
 
-pub fn parse_contains(outer_meta: Meta, cx: &AttrCtxt) -> Result<Expr, ()> {
+pub fn parse_contains(outer_meta: Meta, cx: &AttrCtxt) -> Result<Contains, ()> {
+    let outer_name = path_str(outer_meta.path());
+
     #[derive(Debug)]
     enum ContainsFormat {
         Metas(Punctuated<Meta, Token![,]>),
@@ -233,16 +300,24 @@ pub fn parse_contains(outer_meta: Meta, cx: &AttrCtxt) -> Result<Expr, ()> {
         "garde" => parse_meta_list_with(&outer_meta, cx, Expr::parse).map(ContainsFormat::Expr),
         "schemars" => parse_meta_list_with(&outer_meta, cx, ContainsFormat::parse),
         wat => {
-            unreachable!("Unexpected attr type `{wat}` for `contains` item. This is a bug in schemars, please raise an issue!")
+            unreachable!("Unexpected attr type `{wat}` for `{outer_name}` item. This is a bug in schemars, please raise an issue!")
         }
     }?;
 
     let nested_metas = match nested_meta_or_expr {
-        ContainsFormat::Expr(expr) => return Ok(expr),
+        ContainsFormat::Expr(pattern) => {
+            return Ok(Contains {
+                pattern,
+                min: None,
+                max: None,
+            })
+        }
         ContainsFormat::Metas(m) => m,
     };
 
     let mut pattern = None;
+    let mut min = None;
+    let mut max = None;
 
     for nested_meta in nested_metas {
         match path_str(nested_meta.path()).as_str() {
@@ -250,23 +325,35 @@ pub fn parse_contains(outer_meta: Meta, cx: &AttrCtxt) -> Result<Expr, ()> {
                 Some(_) => cx.duplicate_error(&nested_meta),
                 None => pattern = parse_name_value_expr(nested_meta, cx).ok(),
             },
+            "min" if outer_name == "contains" => match &min {
+                Some(_) => cx.duplicate_error(&nested_meta),
+                None => min = parse_name_value_expr_handle_lit_str(nested_meta, cx).ok(),
+            },
+            "max" if outer_name == "contains" => match &max {
+                Some(_) => cx.duplicate_error(&nested_meta),
+                None => max = parse_name_value_expr_handle_lit_str(nested_meta, cx).ok(),
+            },
             unknown => {
                 if cx.attr_type == "schemars" {
                     cx.error_spanned_by(
                         nested_meta,
-                        format_args!("unknown item in schemars `contains` attribute: `{unknown}`"),
+                        format_args!(
+                            "unknown item in schemars `{outer_name}` attribute: `{unknown}`"
+                        ),
                     );
                 }
             }
         }
     }
 
-    pattern.ok_or_else(|| {
+    let pattern = pattern.ok_or_else(|| {
         cx.error_spanned_by(
             outer_meta,
-            "`contains` attribute item requires `pattern = ...`",
+            format_args!("`{outer_name}` attribute item requires `pattern = ...`"),
         )
-    })
+    })?;
+
+    Ok(Contains { pattern, min, max })
 }
 
 pub fn parse_nested_meta(meta: Meta, cx: &AttrCtxt) -> Result<impl IntoIterator<Item = Meta>, ()> {
@@ -312,6 +399,15 @@ pub struct LengthOrRange {
     pub min: Option<Expr>,
     pub max: Option<Expr>,
     pub equal: Option<Expr>,
+    pub exclusive_min: Option<Expr>,
+    pub exclusive_max: Option<Expr>,
+}
+
+#[derive(Debug)]
+pub struct Contains {
+    pub pattern: Expr,
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
 }
 
 #[derive(Debug)]
@@ -342,3 +438,39 @@ impl Parse for Extension {
         })
     }
 }
+
+#[derive(Debug)]
+pub struct DependentRequired {
+    pub key: LitStr,
+    pub required: Vec<LitStr>,
+}
+
+impl Parse for DependentRequired {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Allow both `foo = [...]` and `"foo" = [...]`, since property names are not always valid
+        // Rust identifiers.
+        let key = if input.peek(LitStr) {
+            input.parse::<LitStr>()?
+        } else {
+            let ident = input.call(Ident::parse_any)?;
+            LitStr::new(&ident.unraw().to_string(), ident.span())
+        };
+        input.parse::<Token![=]>()?;
+
+        let content;
+        bracketed!(content in input);
+        let required = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+
+        if required.is_empty() {
+            return Err(syn::Error::new(
+                content.span(),
+                "Expected at least one required property name",
+            ));
+        }
+
+        Ok(DependentRequired {
+            key,
+            required: required.into_iter().collect(),
+        })
+    }
+}
@@ -10,12 +10,14 @@ use super::get_meta_items;
 // serde_derive_internals to parse for us.
 pub(crate) static SERDE_KEYWORDS: &[&str] = &[
     "rename",
+    "alias",
     "rename_all",
     "rename_all_fields",
     "deny_unknown_fields",
     "tag",
     "content",
     "untagged",
+    "other",
     "default",
     "skip",
     "skip_serializing",
@@ -24,6 +26,9 @@ pub(crate) static SERDE_KEYWORDS: &[&str] = &[
     "flatten",
     "remote",
     "transparent",
+    "from",
+    "try_from",
+    "into",
     // Special case - `bound` is removed from serde attrs, so is only respected when present in
     // schemars attr.
     "bound",
@@ -141,7 +146,7 @@ mod tests {
             #[misc]
             struct MyStruct {
                 /// blah blah blah
-                #[serde(skip_serializing_if = "some_fn", bound = "removed")]
+                #[serde(skip_serializing_if = "some_fn", alias = "other", bound = "removed")]
                 field1: i32,
                 #[serde(serialize_with = "se", deserialize_with = "de")]
                 #[schemars(with = "with", bound = "bound")]
@@ -157,7 +162,7 @@ mod tests {
             #[serde(rename = "overriden", rename_all = "camelCase", default)]
             struct MyStruct {
                 #[doc = r" blah blah blah"]
-                #[serde(skip_serializing_if = "some_fn")]
+                #[serde(skip_serializing_if = "some_fn", alias = "other")]
                 field1: i32,
                 #[schemars(with = "with", bound = "bound")]
                 #[serde(bound = "bound", serialize_with = "se")]
@@ -5,8 +5,9 @@ use crate::idents::SCHEMA;
 
 use super::{
     parse_meta::{
-        parse_contains, parse_length_or_range, parse_nested_meta, parse_pattern,
-        parse_schemars_regex, parse_validate_regex, require_path_only, LengthOrRange,
+        parse_contains, parse_length_or_range, parse_name_value_expr,
+        parse_name_value_expr_handle_lit_str, parse_nested_meta, parse_pattern,
+        parse_schemars_regex, parse_validate_regex, require_path_only, Contains, LengthOrRange,
     },
     AttrCtxt,
 };
@@ -18,6 +19,8 @@ pub enum Format {
     Ip,
     Ipv4,
     Ipv6,
+    CreditCard,
+    PhoneNumber,
 }
 
 impl Format {
@@ -28,16 +31,29 @@ impl Format {
             Format::Ip => "ip",
             Format::Ipv4 => "ipv4",
             Format::Ipv6 => "ipv6",
+            Format::CreditCard => "credit_card",
+            Format::PhoneNumber => "phone_number",
         }
     }
 
-    fn schema_str(self) -> &'static str {
-        match self {
+    fn schema_str(self) -> Option<&'static str> {
+        Some(match self {
             Format::Email => "email",
             Format::Uri => "uri",
             Format::Ip => "ip",
             Format::Ipv4 => "ipv4",
             Format::Ipv6 => "ipv6",
+            Format::CreditCard => return None,
+            Format::PhoneNumber => "phone",
+        })
+    }
+
+    fn schema_pattern(self) -> Option<&'static str> {
+        match self {
+            // Both garde and validator check card numbers using `card-validate`, which only accepts
+            // strings of 12 to 19 digits
+            Format::CreditCard => Some("^[0-9]{12,19}$"),
+            _ => None,
         }
     }
 
@@ -48,6 +64,8 @@ impl Format {
             "ip" => Format::Ip,
             "ipv4" => Format::Ipv4,
             "ipv6" => Format::Ipv6,
+            "credit_card" => Format::CreditCard,
+            "phone_number" => Format::PhoneNumber,
             _ => return None,
         })
     }
@@ -57,12 +75,26 @@ impl Format {
 pub struct ValidationAttrs {
     pub length: Option<LengthOrRange>,
     pub range: Option<LengthOrRange>,
+    pub properties: Option<LengthOrRange>,
+    pub multiple_of: Option<Expr>,
     pub pattern: Option<Expr>,
     pub regex: Option<Expr>,
-    pub contains: Option<Expr>,
+    pub contains: Option<Contains>,
+    pub does_not_contain: Option<Expr>,
+    pub prefix: Option<Expr>,
+    pub suffix: Option<Expr>,
+    pub ascii: bool,
+    pub alphanumeric: bool,
+    pub unique_items: bool,
+    pub non_control_character: bool,
     pub required: bool,
     pub format: Option<Format>,
+    pub custom_format: Option<Expr>,
+    pub content_encoding: Option<Expr>,
+    pub content_media_type: Option<Expr>,
     pub inner: Option<Box<ValidationAttrs>>,
+    pub keys: Option<Box<ValidationAttrs>>,
+    pub values: Option<Box<ValidationAttrs>>,
 }
 
 impl ValidationAttrs {
@@ -78,6 +110,34 @@ impl ValidationAttrs {
 
         if let Some(range) = &self.range {
             Self::add_length_or_range(range, mutators, "number", "imum", mut_ref_schema);
+
+            if let Some(exclusive_min) = &range.exclusive_min {
+                mutators.push(quote!{
+                    schemars::_private::insert_validation_property(#mut_ref_schema, "number", "exclusiveMinimum", #exclusive_min);
+                });
+            }
+
+            if let Some(exclusive_max) = &range.exclusive_max {
+                mutators.push(quote!{
+                    schemars::_private::insert_validation_property(#mut_ref_schema, "number", "exclusiveMaximum", #exclusive_max);
+                });
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            Self::add_length_or_range(properties, mutators, "object", "Properties", mut_ref_schema);
+        }
+
+        if self.unique_items {
+            mutators.push(quote! {
+                schemars::_private::insert_validation_property(#mut_ref_schema, "array", "uniqueItems", true);
+            });
+        }
+
+        if let Some(multiple_of) = &self.multiple_of {
+            mutators.push(quote! {
+                schemars::_private::insert_validation_property(#mut_ref_schema, "number", "multipleOf", #multiple_of);
+            });
         }
 
         if let Some(regex) = self.regex.as_ref().or(self.pattern.as_ref()) {
@@ -86,19 +146,92 @@ impl ValidationAttrs {
             });
         }
 
-        if let Some(contains) = &self.contains {
+        if let Some(Contains { pattern, min, max }) = &self.contains {
+            mutators.push(quote! {
+                schemars::_private::must_contain(#mut_ref_schema, &#pattern.to_string());
+            });
+
+            if let Some(min) = min {
+                mutators.push(quote! {
+                    schemars::_private::insert_contains_count(#mut_ref_schema, "minContains", #min);
+                });
+            }
+
+            if let Some(max) = max {
+                mutators.push(quote! {
+                    schemars::_private::insert_contains_count(#mut_ref_schema, "maxContains", #max);
+                });
+            }
+        }
+
+        if let Some(does_not_contain) = &self.does_not_contain {
+            mutators.push(quote! {
+                schemars::_private::must_not_contain(#mut_ref_schema, &#does_not_contain.to_string());
+            });
+        }
+
+        if let Some(prefix) = &self.prefix {
+            mutators.push(quote! {
+                schemars::_private::must_start_with(#mut_ref_schema, &#prefix.to_string());
+            });
+        }
+
+        if let Some(suffix) = &self.suffix {
+            mutators.push(quote! {
+                schemars::_private::must_end_with(#mut_ref_schema, &#suffix.to_string());
+            });
+        }
+
+        if self.ascii {
+            mutators.push(quote! {
+                schemars::_private::insert_pattern(#mut_ref_schema, r"^[\x00-\x7F]*$");
+            });
+        }
+
+        if self.alphanumeric {
             mutators.push(quote! {
-                schemars::_private::must_contain(#mut_ref_schema, &#contains.to_string());
+                schemars::_private::insert_pattern(#mut_ref_schema, r"^[\p{Alphabetic}\p{N}]*$");
             });
         }
 
-        if let Some(format) = &self.format {
-            let f = format.schema_str();
+        if self.non_control_character {
             mutators.push(quote! {
+                schemars::_private::insert_pattern(#mut_ref_schema, r"^\P{Cc}*$");
+            });
+        }
+
+        if let Some(format) = self.format {
+            if let Some(f) = format.schema_str() {
+                mutators.push(quote! {
                     (#mut_ref_schema).ensure_object().insert("format".into(), #f.into());
-            })
+                });
+            }
+
+            if let Some(p) = format.schema_pattern() {
+                mutators.push(quote! {
+                    schemars::_private::insert_pattern(#mut_ref_schema, #p);
+                });
+            }
         };
 
+        if let Some(custom_format) = &self.custom_format {
+            mutators.push(quote! {
+                (#mut_ref_schema).ensure_object().insert("format".into(), (#custom_format).into());
+            })
+        }
+
+        if let Some(content_encoding) = &self.content_encoding {
+            mutators.push(quote! {
+                schemars::_private::insert_validation_property(#mut_ref_schema, "string", "contentEncoding", #content_encoding);
+            });
+        }
+
+        if let Some(content_media_type) = &self.content_media_type {
+            mutators.push(quote! {
+                schemars::_private::insert_validation_property(#mut_ref_schema, "string", "contentMediaType", #content_media_type);
+            });
+        }
+
         if let Some(inner) = &self.inner {
             let mut inner_mutators = Vec::new();
             inner.add_mutators2(&mut inner_mutators, &quote!(inner_schema));
@@ -109,6 +242,28 @@ impl ValidationAttrs {
                 })
             }
         }
+
+        if let Some(keys) = &self.keys {
+            let mut key_mutators = Vec::new();
+            keys.add_mutators2(&mut key_mutators, &quote!(key_schema));
+
+            if !key_mutators.is_empty() {
+                mutators.push(quote! {
+                    schemars::_private::apply_map_key_validation(#mut_ref_schema, |key_schema| { #(#key_mutators)* });
+                })
+            }
+        }
+
+        if let Some(values) = &self.values {
+            let mut value_mutators = Vec::new();
+            values.add_mutators2(&mut value_mutators, &quote!(value_schema));
+
+            if !value_mutators.is_empty() {
+                mutators.push(quote! {
+                    schemars::_private::apply_map_value_validation(#mut_ref_schema, |value_schema| { #(#value_mutators)* });
+                })
+            }
+        }
     }
 
     fn add_length_or_range(
@@ -164,6 +319,40 @@ impl ValidationAttrs {
                 None => self.range = parse_length_or_range(meta, cx).ok(),
             },
 
+            "properties" if cx.attr_type == "schemars" => match self.properties {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.properties = parse_length_or_range(meta, cx).ok(),
+            },
+
+            "unique_items" if cx.attr_type == "schemars" => {
+                if self.unique_items {
+                    cx.duplicate_error(&meta);
+                } else if require_path_only(meta, cx).is_ok() {
+                    self.unique_items = true;
+                }
+            }
+
+            "multiple_of" if cx.attr_type == "schemars" => match self.multiple_of {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.multiple_of = parse_name_value_expr_handle_lit_str(meta, cx).ok(),
+            },
+
+            "format" if cx.attr_type == "schemars" => match (&self.format, &self.custom_format) {
+                (Some(current), _) => cx.mutual_exclusive_error(&meta, current.attr_str()),
+                (_, Some(_)) => cx.duplicate_error(&meta),
+                (None, None) => self.custom_format = parse_name_value_expr(meta, cx).ok(),
+            },
+
+            "content_encoding" if cx.attr_type == "schemars" => match self.content_encoding {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.content_encoding = parse_name_value_expr(meta, cx).ok(),
+            },
+
+            "content_media_type" if cx.attr_type == "schemars" => match self.content_media_type {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.content_media_type = parse_name_value_expr(meta, cx).ok(),
+            },
+
             "required" => {
                 if self.required {
                     cx.duplicate_error(&meta);
@@ -201,6 +390,44 @@ impl ValidationAttrs {
                 (None, None, None) => self.contains = parse_contains(meta, cx).ok(),
             },
 
+            "does_not_contain" if cx.attr_type != "garde" => match self.does_not_contain {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.does_not_contain = parse_contains(meta, cx).ok().map(|c| c.pattern),
+            },
+
+            "prefix" if cx.attr_type != "validate" => match self.prefix {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.prefix = parse_pattern(meta, cx).ok(),
+            },
+            "suffix" if cx.attr_type != "validate" => match self.suffix {
+                Some(_) => cx.duplicate_error(&meta),
+                None => self.suffix = parse_pattern(meta, cx).ok(),
+            },
+
+            "ascii" if cx.attr_type != "validate" => {
+                if self.ascii {
+                    cx.duplicate_error(&meta);
+                } else if require_path_only(meta, cx).is_ok() {
+                    self.ascii = true;
+                }
+            }
+            "alphanumeric" if cx.attr_type != "validate" => {
+                if self.alphanumeric {
+                    cx.duplicate_error(&meta);
+                } else if require_path_only(meta, cx).is_ok() {
+                    self.alphanumeric = true;
+                }
+            }
+
+            "non_control_character" if cx.attr_type != "garde" => {
+                if self.non_control_character {
+                    cx.duplicate_error(&meta);
+                } else if cx.attr_type == "validate" || require_path_only(meta, cx).is_ok() {
+                    // Allow a MetaList in validator attr (e.g. with message/code items)
+                    self.non_control_character = true;
+                }
+            }
+
             "inner" if cx.attr_type != "validate" => {
                 if let Ok(nested_meta) = parse_nested_meta(meta, cx) {
                     let inner = self
@@ -211,6 +438,26 @@ impl ValidationAttrs {
                 }
             }
 
+            "keys" if cx.attr_type == "schemars" => {
+                if let Ok(nested_meta) = parse_nested_meta(meta, cx) {
+                    let keys = self
+                        .keys
+                        .get_or_insert_with(|| Box::new(ValidationAttrs::default()));
+                    let mut keys_cx = cx.new_nested_meta(nested_meta.into_iter().collect());
+                    keys.process_attr(&mut keys_cx);
+                }
+            }
+
+            "values" if cx.attr_type == "schemars" => {
+                if let Ok(nested_meta) = parse_nested_meta(meta, cx) {
+                    let values = self
+                        .values
+                        .get_or_insert_with(|| Box::new(ValidationAttrs::default()));
+                    let mut values_cx = cx.new_nested_meta(nested_meta.into_iter().collect());
+                    values.process_attr(&mut values_cx);
+                }
+            }
+
             _ => return Some(meta),
         }
 
@@ -221,6 +468,7 @@ impl ValidationAttrs {
         match self.format {
             Some(current) if current == format => cx.duplicate_error(&meta),
             Some(current) => cx.mutual_exclusive_error(&meta, current.attr_str()),
+            None if self.custom_format.is_some() => cx.mutual_exclusive_error(&meta, "format"),
             None => {
                 // Allow a MetaList in validator attr (e.g. with message/code items),
                 // but restrict it to path only in schemars attr.
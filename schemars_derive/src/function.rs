@@ -0,0 +1,283 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, FnArg, Ident, ItemFn, Meta, Pat, ReturnType};
+
+// Attributes on the function or its parameters that are moved onto the generated arguments struct.
+// `doc` attributes on the function are copied rather than moved.
+const ARGS_ATTRS: [&str; 4] = ["schemars", "serde", "validate", "garde"];
+
+pub fn expand_function(args: TokenStream, mut item: ItemFn) -> syn::Result<TokenStream> {
+    if !args.is_empty() {
+        return Err(syn::Error::new_spanned(
+            args,
+            "#[schemars::function] does not take any arguments - use #[schemars(...)] or #[serde(...)] on the function instead",
+        ));
+    }
+
+    let sig = &item.sig;
+    if let Some(unsafety) = &sig.unsafety {
+        return Err(syn::Error::new_spanned(
+            unsafety,
+            "#[schemars::function] cannot be used on unsafe functions",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            "#[schemars::function] cannot be used on generic functions",
+        ));
+    }
+    if let Some(variadic) = &sig.variadic {
+        return Err(syn::Error::new_spanned(
+            variadic,
+            "#[schemars::function] cannot be used on variadic functions",
+        ));
+    }
+
+    if let ReturnType::Type(_, ty) = &sig.output {
+        if contains_self(ty.to_token_stream()) {
+            return Err(associated_fn_error(ty));
+        }
+    }
+
+    let fn_name = sig.ident.clone();
+    let fn_name_str = fn_name.to_string();
+    let fn_name_str = fn_name_str.trim_start_matches("r#");
+    let args_ident = Ident::new(&args_struct_name(fn_name_str), fn_name.span());
+    let vis = item.vis.clone();
+
+    let mut container_attrs: Vec<Attribute> = Vec::new();
+    item.attrs.retain(|attr| {
+        if attr.path().is_ident("doc") {
+            container_attrs.push(attr.clone());
+            true
+        } else if is_args_attr(attr) {
+            container_attrs.push(attr.clone());
+            false
+        } else {
+            true
+        }
+    });
+    if !has_rename(&container_attrs) {
+        container_attrs.push(parse_quote!(#[schemars(rename = #fn_name_str)]));
+    }
+
+    let mut fields = Vec::new();
+    let mut field_idents = Vec::new();
+    for input in &mut item.sig.inputs {
+        let arg = match input {
+            FnArg::Typed(arg) => arg,
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "#[schemars::function] cannot be used on methods with a `self` parameter",
+                ))
+            }
+        };
+        let ident = match &*arg.pat {
+            Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => pat.ident.clone(),
+            pat => {
+                return Err(syn::Error::new_spanned(
+                    pat,
+                    "#[schemars::function] requires each parameter to be a plain identifier",
+                ))
+            }
+        };
+
+        check_param_type(&arg.ty)?;
+
+        let mut field_attrs = Vec::new();
+        arg.attrs.retain(|attr| {
+            if attr.path().is_ident("doc") || is_args_attr(attr) {
+                field_attrs.push(attr.clone());
+                false
+            } else {
+                true
+            }
+        });
+
+        let ty = &arg.ty;
+        fields.push(quote! {
+            #(#field_attrs)*
+            #vis #ident: #ty
+        });
+        field_idents.push(ident);
+    }
+
+    let args_struct: syn::DeriveInput = parse_quote! {
+        #(#container_attrs)*
+        #vis struct #args_ident {
+            #(#fields,)*
+        }
+    };
+
+    let json_schema_impl = crate::derive_json_schema(args_struct.clone(), false)?;
+    let crate_alias = crate_name(&args_struct.attrs).map(|path| {
+        quote! {
+            use #path as schemars;
+        }
+    });
+
+    let mut emitted_struct = args_struct;
+    emitted_struct.attrs.retain(|a| !is_schemars_only_attr(a));
+    if let syn::Data::Struct(data) = &mut emitted_struct.data {
+        for field in &mut data.fields {
+            field.attrs.retain(|a| !is_schemars_only_attr(a));
+        }
+    }
+
+    let output = match &item.sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    let (asyncness, dot_await) = match &item.sig.asyncness {
+        Some(asyncness) => (Some(asyncness), Some(quote!(.await))),
+        None => (None, None),
+    };
+    let call_doc = format!("Calls [`{fn_name_str}`] with these arguments.");
+    let call_json_doc = format!(
+        "Deserializes the arguments from a JSON object and calls [`{fn_name_str}`] with them."
+    );
+
+    Ok(quote! {
+        #item
+
+        #[derive(serde::Deserialize)]
+        #emitted_struct
+
+        #json_schema_impl
+
+        const _: () = {
+            #crate_alias
+
+            #[automatically_derived]
+            impl #args_ident {
+                #[doc = #call_doc]
+                #vis #asyncness fn call(self) -> #output {
+                    #fn_name(#(self.#field_idents),*)#dot_await
+                }
+
+                #[doc = #call_json_doc]
+                #vis #asyncness fn call_json(
+                    args: schemars::_private::serde_json::Value,
+                ) -> ::core::result::Result<#output, schemars::_private::serde_json::Error> {
+                    let args: Self = schemars::_private::serde_json::from_value(args)?;
+                    ::core::result::Result::Ok(args.call()#dot_await)
+                }
+            }
+        };
+    })
+}
+
+// Each parameter becomes a field of the (non-generic) arguments struct, so its type must be owned
+// and nameable.
+fn check_param_type(ty: &syn::Type) -> syn::Result<()> {
+    fn check(tokens: TokenStream, ty: &syn::Type) -> syn::Result<()> {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                // `'static` is the only lifetime that a deserialized field can have
+                TokenTree::Punct(p)
+                    if p.as_char() == '\''
+                        && !matches!(tokens.peek(), Some(TokenTree::Ident(i)) if i == "static") =>
+                {
+                    return Err(borrowed_param_error(ty));
+                }
+                TokenTree::Punct(p) if p.as_char() == '&' => return Err(borrowed_param_error(ty)),
+                TokenTree::Ident(i) if i == "impl" => {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "#[schemars::function] cannot be used with `impl Trait` parameters",
+                    ));
+                }
+                TokenTree::Ident(i) if i == "Self" => return Err(associated_fn_error(ty)),
+                TokenTree::Group(g) => check(g.stream(), ty)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    check(ty.to_token_stream(), ty)
+}
+
+fn borrowed_param_error(ty: &syn::Type) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        "#[schemars::function] cannot be used with borrowed parameters - use an owned type instead, e.g. `String` instead of `&str`",
+    )
+}
+
+fn contains_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(i) => i == "Self",
+        TokenTree::Group(g) => contains_self(g.stream()),
+        _ => false,
+    })
+}
+
+fn associated_fn_error(tokens: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        "#[schemars::function] cannot be used on associated functions - move the function out of the `impl` block",
+    )
+}
+
+fn args_struct_name(fn_name: &str) -> String {
+    let mut result = String::with_capacity(fn_name.len() + 4);
+    for word in fn_name.split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    result.push_str("Args");
+    result
+}
+
+fn is_args_attr(attr: &Attribute) -> bool {
+    ARGS_ATTRS.iter().any(|a| attr.path().is_ident(a))
+}
+
+// Attributes that are only understood by `derive(JsonSchema)`, so must not be emitted on the
+// generated struct (which only derives `Deserialize`).
+fn is_schemars_only_attr(attr: &Attribute) -> bool {
+    ["schemars", "validate", "garde"]
+        .iter()
+        .any(|a| attr.path().is_ident(a))
+}
+
+fn has_rename(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("schemars") || a.path().is_ident("serde"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("rename"))
+}
+
+fn crate_name(attrs: &[Attribute]) -> Option<syn::Path> {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("schemars"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|meta| match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("crate") => match nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) => lit.parse().ok(),
+                _ => None,
+            },
+            _ => None,
+        })
+}
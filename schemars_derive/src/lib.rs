@@ -8,6 +8,7 @@ extern crate proc_macro;
 
 mod ast;
 mod attr;
+mod function;
 mod idents;
 mod schema_exprs;
 
@@ -34,6 +35,32 @@ pub fn derive_json_schema_repr_wrapper(input: proc_macro::TokenStream) -> proc_m
         .into()
 }
 
+/// Attribute macro that generates a `JsonSchema`-implementing struct describing a function's
+/// arguments, e.g. for use as the parameters of an LLM tool/function call.
+///
+/// For a function named `get_weather`, this generates a struct named `GetWeatherArgs` with one
+/// field per parameter, whose schema has the name `get_weather` and the function's doc comment as
+/// its description. `Option` parameters are not required. Parameters may use the same
+/// `#[schemars(...)]`/`#[serde(...)]` attributes as fields of a struct deriving `JsonSchema`, and
+/// the function may use the same attributes as a container.
+///
+/// The generated struct also implements `serde::Deserialize` (so the `serde` crate must be a
+/// dependency, with its `derive` feature enabled), and has `call()` and `call_json()` methods to
+/// call the function with the deserialized arguments.
+///
+/// This must be used on a free function (not an associated function in an `impl` block) whose
+/// parameters all have owned types, e.g. `String` rather than `&str`.
+#[proc_macro_attribute]
+pub fn function(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::ItemFn);
+    function::expand_function(args.into(), input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 fn derive_json_schema(mut input: syn::DeriveInput, repr: bool) -> syn::Result<TokenStream> {
     attr::process_serde_attrs(&mut input)?;
 
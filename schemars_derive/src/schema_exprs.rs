@@ -14,6 +14,9 @@ pub struct SchemaExpr {
     mutators: Vec<TokenStream>,
 }
 
+/// Schemas for an enum's variants, along with the variant that each schema is for (if any)
+type VariantSchemas<'a> = Vec<(Option<&'a Variant<'a>>, SchemaExpr)>;
+
 impl From<TokenStream> for SchemaExpr {
     fn from(creator: TokenStream) -> Self {
         Self {
@@ -49,7 +52,41 @@ impl ToTokens for SchemaExpr {
 }
 
 pub fn expr_for_container(cont: &Container) -> SchemaExpr {
-    let mut schema_expr = match &cont.data {
+    let from_type = cont
+        .serde_attrs
+        .type_from()
+        .or(cont.serde_attrs.type_try_from());
+    let into_type = cont.serde_attrs.type_into();
+
+    let mut schema_expr = if from_type.is_none() && into_type.is_none() {
+        expr_for_data(cont)
+    } else {
+        // Serde (de)serializes the container via the `from`/`try_from`/`into` type instead of
+        // via its own fields, so use that type's schema for the relevant contract.
+        let type_schema_expr = |ty: Option<&syn::Type>| match ty {
+            Some(ty) => quote_spanned! {ty.span()=>
+                #GENERATOR.subschema_for::<#ty>()
+            },
+            None => expr_for_data(cont).into_token_stream(),
+        };
+        let de_schema_expr = type_schema_expr(from_type);
+        let ser_schema_expr = type_schema_expr(into_type);
+
+        SchemaExpr::from(quote! {
+            if #GENERATOR.contract().is_deserialize() {
+                #de_schema_expr
+            } else {
+                #ser_schema_expr
+            }
+        })
+    };
+
+    cont.add_mutators(&mut schema_expr.mutators);
+    schema_expr
+}
+
+fn expr_for_data(cont: &Container) -> SchemaExpr {
+    match &cont.data {
         Data::Struct(Style::Unit, _) => expr_for_unit_struct(),
         Data::Struct(Style::Newtype, fields) => expr_for_newtype_struct(&fields[0]),
         Data::Struct(Style::Tuple, fields) => expr_for_tuple_struct(fields),
@@ -58,11 +95,10 @@ pub fn expr_for_container(cont: &Container) -> SchemaExpr {
             cont.serde_attrs.default(),
             cont.serde_attrs.deny_unknown_fields(),
         ),
-        Data::Enum(variants) => expr_for_enum(variants, &cont.serde_attrs),
-    };
-
-    cont.add_mutators(&mut schema_expr.mutators);
-    schema_expr
+        Data::Enum(variants) => {
+            expr_for_enum(variants, &cont.serde_attrs, cont.attrs.discriminator)
+        }
+    }
 }
 
 pub fn expr_for_repr(cont: &Container) -> Result<SchemaExpr, syn::Error> {
@@ -183,29 +219,88 @@ fn type_for_schema(with_attr: &WithAttr) -> (syn::Type, Option<TokenStream>) {
     }
 }
 
-fn expr_for_enum(variants: &[Variant], cattrs: &serde_attr::Container) -> SchemaExpr {
+fn expr_for_enum(
+    variants: &[Variant],
+    cattrs: &serde_attr::Container,
+    discriminator: bool,
+) -> SchemaExpr {
     if variants.is_empty() {
         return quote!(schemars::Schema::from(false)).into();
     }
     let deny_unknown_fields = cattrs.deny_unknown_fields();
-    let variants = variants.iter();
+    let known_tags = known_variant_tags(variants);
 
-    match cattrs.tag() {
-        TagType::External => expr_for_external_tagged_enum(variants, deny_unknown_fields),
-        TagType::None => expr_for_untagged_enum(variants, deny_unknown_fields),
-        TagType::Internal { tag } => {
-            expr_for_internal_tagged_enum(variants, tag, deny_unknown_fields)
-        }
-        TagType::Adjacent { tag, content } => {
-            expr_for_adjacent_tagged_enum(variants, tag, content, deny_unknown_fields)
+    // Variants marked `#[serde(untagged)]` are tried (in order) after all tagged variants
+    let (tagged_variants, untagged_variants): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .partition(|v| !v.serde_attrs.untagged() || matches!(cattrs.tag(), TagType::None));
+    let tagged_variants = tagged_variants.into_iter();
+
+    let mut schemas = match cattrs.tag() {
+        TagType::External => {
+            variant_schemas_for_external_tagged_enum(tagged_variants, deny_unknown_fields)
         }
+        TagType::None => variant_schemas_for_untagged_enum(tagged_variants, deny_unknown_fields),
+        TagType::Internal { tag } => variant_schemas_for_internal_tagged_enum(
+            tagged_variants,
+            tag,
+            &known_tags,
+            deny_unknown_fields,
+            discriminator,
+        ),
+        TagType::Adjacent { tag, content } => variant_schemas_for_adjacent_tagged_enum(
+            tagged_variants,
+            tag,
+            content,
+            &known_tags,
+            deny_unknown_fields,
+        ),
+    };
+
+    schemas.extend(variant_schemas_for_untagged_enum(
+        untagged_variants.into_iter(),
+        deny_unknown_fields,
+    ));
+
+    // An externally tagged enum with only unit variants is a single string enum schema
+    if let [(None, _)] = schemas.as_slice() {
+        return schemas.pop().unwrap().1;
+    }
+
+    // Untagged variants can easily have schemas that overlap with other variants; rather than
+    // checking the exclusivity of each subschema we simply use `anyOf`.
+    let unique = !matches!(cattrs.tag(), TagType::None)
+        && variants.iter().all(|v| !v.serde_attrs.untagged());
+    let mut schema_expr = variant_subschemas(unique, schemas);
+
+    if let (true, TagType::Internal { tag }) = (discriminator, cattrs.tag()) {
+        schema_expr.mutators.push(quote! {
+            schemars::_private::insert_discriminator(#GENERATOR, &mut #SCHEMA, #tag);
+        });
     }
+
+    schema_expr
+}
+
+/// Returns the names and aliases that serde recognises as tags of the enum's variants when
+/// deserializing, excluding any `#[serde(other)]` variant.
+fn known_variant_tags<'a>(variants: &'a [Variant]) -> Vec<&'a str> {
+    variants
+        .iter()
+        .filter(|v| {
+            !v.serde_attrs.other()
+                && !v.serde_attrs.untagged()
+                && !v.serde_attrs.skip_deserializing()
+        })
+        .flat_map(|v| v.serde_attrs.aliases())
+        .map(String::as_str)
+        .collect()
 }
 
-fn expr_for_external_tagged_enum<'a>(
+fn variant_schemas_for_external_tagged_enum<'a>(
     variants: impl Iterator<Item = &'a Variant<'a>>,
     deny_unknown_fields: bool,
-) -> SchemaExpr {
+) -> VariantSchemas<'a> {
     let (unit_variants, complex_variants): (Vec<_>, Vec<_>) =
         variants.partition(|v| v.is_unit() && v.attrs.is_default());
     let add_unit_names = unit_variants.iter().map(|v| {
@@ -228,10 +323,6 @@ fn expr_for_external_tagged_enum<'a>(
         schemars::Schema::from(map)
     }));
 
-    if complex_variants.is_empty() {
-        return unit_schema;
-    }
-
     let mut schemas = Vec::new();
     if !unit_variants.is_empty() {
         schemas.push((None, unit_schema));
@@ -257,15 +348,17 @@ fn expr_for_external_tagged_enum<'a>(
         (Some(variant), schema_expr)
     }));
 
-    variant_subschemas(true, schemas)
+    schemas
 }
 
-fn expr_for_internal_tagged_enum<'a>(
+fn variant_schemas_for_internal_tagged_enum<'a>(
     variants: impl Iterator<Item = &'a Variant<'a>>,
     tag_name: &str,
+    known_tags: &[&str],
     deny_unknown_fields: bool,
-) -> SchemaExpr {
-    let variant_schemas = variants
+    discriminator: bool,
+) -> VariantSchemas<'a> {
+    variants
         .map(|variant| {
 
             let mut schema_expr = expr_for_internal_tagged_enum_variant(variant, deny_unknown_fields);
@@ -275,20 +368,48 @@ fn expr_for_internal_tagged_enum<'a>(
                 schemars::_private::apply_internal_enum_variant_tag(&mut #SCHEMA, #tag_name, #name, #deny_unknown_fields);
             ));
 
+            if variant.serde_attrs.other() {
+                schema_expr.mutators.push(quote! {
+                    if #GENERATOR.contract().is_deserialize() {
+                        schemars::_private::insert_object_property(
+                            &mut #SCHEMA,
+                            #tag_name,
+                            true,
+                            schemars::_private::other_enum_variant_tag_schema(&[#(#known_tags),*]),
+                        );
+                    }
+                });
+            }
+
             variant.add_mutators(&mut schema_expr.mutators);
 
+            if discriminator {
+                // Variants must be named definitions so that they can be referenced by the
+                // discriminator's `mapping`
+                let variant_ident = variant.ident.to_string();
+                schema_expr.mutators.push(quote! {
+                    #SCHEMA = schemars::_private::new_discriminator_variant_ref(
+                        #GENERATOR,
+                        &schemars::_private::alloc::format!(
+                            "{}{}",
+                            <Self as schemars::JsonSchema>::schema_name(),
+                            #variant_ident
+                        ),
+                        #SCHEMA,
+                    );
+                });
+            }
+
             (Some(variant), schema_expr)
         })
-        .collect();
-
-    variant_subschemas(true, variant_schemas)
+        .collect()
 }
 
-fn expr_for_untagged_enum<'a>(
+fn variant_schemas_for_untagged_enum<'a>(
     variants: impl Iterator<Item = &'a Variant<'a>>,
     deny_unknown_fields: bool,
-) -> SchemaExpr {
-    let schemas = variants
+) -> VariantSchemas<'a> {
+    variants
         .map(|variant| {
             let mut schema_expr = expr_for_untagged_enum_variant(variant, deny_unknown_fields);
 
@@ -296,20 +417,17 @@ fn expr_for_untagged_enum<'a>(
 
             (Some(variant), schema_expr)
         })
-        .collect();
-
-    // Untagged enums can easily have variants whose schemas overlap; rather
-    // that checking the exclusivity of each subschema we simply us `any_of`.
-    variant_subschemas(false, schemas)
+        .collect()
 }
 
-fn expr_for_adjacent_tagged_enum<'a>(
+fn variant_schemas_for_adjacent_tagged_enum<'a>(
     variants: impl Iterator<Item = &'a Variant<'a>>,
     tag_name: &str,
     content_name: &str,
+    known_tags: &[&str],
     deny_unknown_fields: bool,
-) -> SchemaExpr {
-    let schemas = variants
+) -> VariantSchemas<'a> {
+    variants
         .map(|variant| {
             let content_schema = if variant.is_unit() && variant.attrs.with.is_none() {
                 None
@@ -327,12 +445,21 @@ fn expr_for_adjacent_tagged_enum<'a>(
                 .unwrap_or_default();
 
             let name = variant.name();
-            let tag_schema = quote! {
+            let mut tag_schema = quote! {
                 schemars::json_schema!({
                     "type": "string",
                     "const": #name,
                 })
             };
+            if variant.serde_attrs.other() {
+                tag_schema = quote! {
+                    if #GENERATOR.contract().is_deserialize() {
+                        schemars::_private::other_enum_variant_tag_schema(&[#(#known_tags),*])
+                    } else {
+                        #tag_schema
+                    }
+                };
+            }
 
             let set_additional_properties = if deny_unknown_fields {
                 quote! {
@@ -361,14 +488,12 @@ fn expr_for_adjacent_tagged_enum<'a>(
 
             (Some(variant), outer_schema)
         })
-        .collect();
-
-    variant_subschemas(true, schemas)
+        .collect()
 }
 
 /// Callers must determine if all subschemas are mutually exclusive. The current behaviour is to
 /// assume that variants are mutually exclusive except for untagged enums.
-fn variant_subschemas(unique: bool, schemas: Vec<(Option<&Variant>, SchemaExpr)>) -> SchemaExpr {
+fn variant_subschemas(unique: bool, schemas: VariantSchemas) -> SchemaExpr {
     let keyword = if unique { "oneOf" } else { "anyOf" };
     let add_schemas = schemas.into_iter().map(|(v, s)| {
         let add = quote! {
@@ -540,11 +665,34 @@ fn expr_for_struct(
                     })
                 }
 
+                let de_name = field.serde_attrs.name().deserialize_name();
+                let aliases: Vec<&str> = field
+                    .serde_attrs
+                    .aliases()
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|a| *a != de_name)
+                    .collect();
+                let insert_property = if aliases.is_empty() {
+                    quote! {
+                        schemars::_private::insert_object_property(&mut #SCHEMA, #name, #is_optional, #schema_expr);
+                    }
+                } else {
+                    quote! {
+                        let aliases: &[&str] = if #GENERATOR.contract().is_deserialize() {
+                            &[#(#aliases),*]
+                        } else {
+                            &[]
+                        };
+                        schemars::_private::insert_object_property_with_aliases(&mut #SCHEMA, #name, aliases, #is_optional, #schema_expr);
+                    }
+                };
+
                 // embed `#type_def` outside of `#schema_expr`, because it's used as a type param
                 // in `#is_optional` (`#type_def` is the definition of `#ty`)
                 field.with_contract_check(quote!({
                     #type_def
-                    schemars::_private::insert_object_property(&mut #SCHEMA, #name, #is_optional, #schema_expr);
+                    #insert_property
                 }))
             }
             })